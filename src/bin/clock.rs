@@ -1,6 +1,6 @@
 use std::f64::consts::PI;
 
-use book_renderer::{canvas::Canvas, tuple::Tuple, util::Float, color::GREEN, matrix::Matrix};
+use book_renderer::{canvas::Canvas, tuple::Tuple, util::Float, color::GREEN, matrix4::Matrix4};
 use clap::Parser;
 
 #[derive(Debug, Parser)]
@@ -25,7 +25,7 @@ fn main() {
     let noon = Tuple::new_point(0.0, -100.0, 0.0);
     for i in 0..12 {
         let rads = (PI / 6.0) * (i as f64);
-        let time = Matrix::rotation_z(rads) * noon;
+        let time = Matrix4::rotation_z(rads) * noon;
         draw_dot(&mut canvas, &time);
     }
 
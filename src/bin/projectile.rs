@@ -171,7 +171,7 @@ impl Simulator {
     }
 
     fn find_bounds(&self) -> Result<Bounds, Box<dyn Error>> {
-        let mut p = self.p.clone();
+        let mut p = self.p;
         let mut bounds = Bounds::new(p.position.x.round() as i16, p.position.y.round() as i16);
         while p.position.y > 0.0 {
             p = tick(&p, &self.e);
@@ -191,7 +191,7 @@ impl Simulator {
             let (x,y) = bounds.position_to_canvas(self.p.position.x.round() as i16, self.p.position.y.round() as i16);
             canvas.pixels[(y,x)] = color::Color::new(1.0, 0.5, 0.2);
             self.terminal.as_mut().ok_or("No terminal")?.draw(|f| {
-                let data: Vec<String> = vec![self.p.position, self.p.velocity, self.e.gravity, self.e.wind].iter().map(String::from).collect();
+                let data: Vec<String> = [self.p.position, self.p.velocity, self.e.gravity, self.e.wind].iter().map(String::from).collect();
                 // let data = vec!["1,1","2,2","3,3","4,4"];
                 let data_rows = vec![Row::new(data)];
                 let header = Row::new(vec!["Position", "Velocity", "Gravity", "Wind"]).style(Style::default().fg(Color::Yellow)).bottom_margin(1);
@@ -0,0 +1,48 @@
+use book_renderer::scene::Scene;
+use clap::Parser;
+
+#[derive(Debug, Parser)]
+struct Args {
+    /// Scene description file (see `scenes/` for examples)
+    scene: String,
+
+    /// Where to write the image; `.ppm` files are written as PPM, anything else as PNG
+    outfile: String,
+
+    /// Number of render threads (defaults to one per CPU core)
+    #[arg(long)]
+    threads: Option<usize>,
+
+    /// How many times rays may be reflected or refracted
+    #[arg(long)]
+    max_depth: Option<usize>,
+}
+
+fn main() {
+    // so warnings about bad lines in OBJ files are shown
+    structured_logger::Builder::with_level("warn").init();
+    let args = Args::parse();
+    let mut scene = match Scene::load(&args.scene) {
+        Ok(scene) => scene,
+        Err(e) => {
+            eprintln!("{}: {}", args.scene, e);
+            std::process::exit(1);
+        },
+    };
+    if let Some(threads) = args.threads {
+        scene.camera.set_threads(threads);
+    }
+    if let Some(max_depth) = args.max_depth {
+        scene.world.max_depth = max_depth;
+    }
+    let canvas = scene.camera.render(&scene.world);
+    let written = if args.outfile.ends_with(".ppm") {
+        canvas.write_ppm(&args.outfile)
+    } else {
+        canvas.write_png(&args.outfile)
+    };
+    if let Err(e) = written {
+        eprintln!("{}: {}", args.outfile, e);
+        std::process::exit(1);
+    }
+}
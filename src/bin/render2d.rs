@@ -1,4 +1,4 @@
-use book_renderer::{tuple::Point, canvas::Canvas, sphere::Sphere, matrix::Matrix, ray::Ray, color::{Color, BLACK}, intersectable::Intersectable, intersection::Intersection};
+use book_renderer::{tuple::Point, canvas::Canvas, sphere::Sphere, matrix4::Matrix4, ray::Ray, color::{Color, BLACK}, intersectable::Intersectable, intersection::Intersection};
 use clap::Parser;
 
 #[derive(Debug, Parser)]
@@ -27,14 +27,14 @@ fn main() {
     let canvas_pixels = args.canvas_size as f64;
     let pixel_size = wall_size / canvas_pixels;
     let half_wall = wall_size / 2.0;
-    shape.set_transform(Matrix::translation(args.sphere.x, args.sphere.y, args.sphere.z) * Matrix::scaling(2.0, 1.0, 1.0));
+    shape.set_transform(Matrix4::identity().scale(2.0, 1.0, 1.0).translate(args.sphere.x, args.sphere.y, args.sphere.z)).unwrap();
     for canvas_y in 0..args.canvas_size {
         let world_y = half_wall - pixel_size * (canvas_y as f64);
         for canvas_x in 0..args.canvas_size {
             let world_x = -half_wall + pixel_size * (canvas_x as f64);
-            let position = Point::new_point(world_x, world_y, args.canvas_z as f64);
-            let origin = args.camera.clone();
-            let ray = Ray::new(origin, &position - &origin);
+            let position = Point::new_point(world_x, world_y, args.canvas_z);
+            let origin = args.camera;
+            let ray = Ray::new(origin, position - origin);
             let intersections = shape.intersections(&ray);
             match Intersection::hit(&intersections) {
                 Some(_) => canvas[(canvas_x, canvas_y)] = Color::new(50.0, 0.0, 0.0),
@@ -1,4 +1,4 @@
-use book_renderer::{tuple::Point, canvas::Canvas, sphere::Sphere, matrix::Matrix, ray::Ray, color::{Color, BLACK}, light::Light, intersection::Intersection, intersectable::Intersectable};
+use book_renderer::{tuple::{Point, Tuple}, sphere::Sphere, matrix4::Matrix4, color::Color, light::Light, world::World, camera::Camera, intersectable::Intersectable};
 use clap::Parser;
 
 #[derive(Debug, Parser)]
@@ -6,6 +6,9 @@ struct Args {
     #[arg(long, default_value = "0,0,-20", value_parser = Point::parse_point)]
     camera: Point,
 
+    #[arg(long, default_value = "0,0,0", value_parser = Point::parse_point)]
+    look_at: Point,
+
     #[arg(long, default_value = "0,0,-10", value_parser = Point::parse_point)]
     sphere: Point,
 
@@ -15,48 +18,34 @@ struct Args {
     #[arg(long, default_value = "1")]
     light_intensity: f64,
 
-    #[arg(long, default_value = "0")]
-    canvas_z: f64,
+    #[arg(long, default_value = "0.4")]
+    field_of_view: f64,
 
     #[arg(long, default_value = "100")]
     canvas_size: usize,
 
+    /// Number of render threads (defaults to one per CPU core)
+    #[arg(long)]
+    threads: Option<usize>,
+
     #[arg(long, default_value = "/tmp/canvas.png")]
     outfile: String,
 }
 
 fn main() {
     let args = Args::parse();
-    let mut canvas = Canvas::new(args.canvas_size, args.canvas_size);
     let mut shape = Sphere::new_unit();
-    let wall_size = 8.0;
-    let canvas_pixels = args.canvas_size as f64;
-    let pixel_size = wall_size / canvas_pixels;
-    let half_wall = wall_size / 2.0;
-    shape.set_transform(Matrix::translation(args.sphere.x, args.sphere.y, args.sphere.z));
-    shape.material.color.green = 0.2;
-    shape.material.specular = 2.0;
-    let light = Light::new_point(args.light, Color::white(args.light_intensity));
-    for canvas_y in 0..args.canvas_size {
-        let world_y = half_wall - pixel_size * (canvas_y as f64);
-        for canvas_x in 0..args.canvas_size {
-            let world_x = -half_wall + pixel_size * (canvas_x as f64);
-            let position = Point::new_point(world_x, world_y, args.canvas_z as f64);
-            let origin = args.camera.clone();
-            let ray = Ray::new(origin, (&position - &origin).normalize().unwrap());
-            let intersections = shape.intersections(&ray);
-            match Intersection::hit(&intersections) {
-                Some(hit) => {
-                    let hit_point = ray.position(&hit.t);
-                    let hit_normal = hit.object.surface_normal(&hit_point);
-                    let eye = -ray.direction;
-                    let color = hit.object.material().light(&light, &hit_point, &eye, &hit_normal);
-                    canvas[(canvas_x, canvas_y)] = color
-                },
-                None => 
-                    canvas[(canvas_x, canvas_y)] = BLACK,
-            }
-        }
+    shape.set_transform(Matrix4::translation(args.sphere.x, args.sphere.y, args.sphere.z)).unwrap();
+    shape.mut_material().color.green = 0.2;
+    shape.mut_material().specular = 2.0;
+    let world = World::new(
+        vec![Light::new_point(args.light, Color::white(args.light_intensity))],
+        vec![Box::new(shape)],
+    );
+    let mut camera = Camera::new(args.canvas_size, args.canvas_size, args.field_of_view);
+    if let Some(threads) = args.threads {
+        camera.set_threads(threads);
     }
-    canvas.write_png(args.outfile).unwrap();
-}
\ No newline at end of file
+    camera.set_transform(Matrix4::view_transform(&args.camera, &args.look_at, &Tuple::new_vector(0.0, 1.0, 0.0)).unwrap()).unwrap();
+    camera.render(&world).write_png(args.outfile).unwrap();
+}
@@ -0,0 +1,175 @@
+use crate::{
+    matrix4::Matrix4,
+    ray::Ray,
+    tuple::{Point, Tuple},
+    util::{Float, EPSILON},
+};
+
+// From the book's online bonus chapter, "Bounding boxes and hierarchies"
+// In book: bounding_box(min, max)
+// An axis-aligned box around a shape. Shapes that go on forever (planes, open cylinders) have
+// infinite sides. A box with nothing in it has its minimum above its maximum, so adding the
+// first point makes it exactly that point.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Default for BoundingBox {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl BoundingBox {
+    pub fn new(min: Point, max: Point) -> BoundingBox {
+        BoundingBox { min, max }
+    }
+
+    pub fn empty() -> BoundingBox {
+        BoundingBox::new(
+            Tuple::new_point(Float::INFINITY, Float::INFINITY, Float::INFINITY),
+            Tuple::new_point(Float::NEG_INFINITY, Float::NEG_INFINITY, Float::NEG_INFINITY),
+        )
+    }
+
+    // A box around everything
+    pub fn infinite() -> BoundingBox {
+        BoundingBox::new(
+            Tuple::new_point(Float::NEG_INFINITY, Float::NEG_INFINITY, Float::NEG_INFINITY),
+            Tuple::new_point(Float::INFINITY, Float::INFINITY, Float::INFINITY),
+        )
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    // In book: add_point(box, point)
+    pub fn add_point(&mut self, point: &Point) {
+        self.min = Tuple::new_point(self.min.x.min(point.x), self.min.y.min(point.y), self.min.z.min(point.z));
+        self.max = Tuple::new_point(self.max.x.max(point.x), self.max.y.max(point.y), self.max.z.max(point.z));
+    }
+
+    // In book: add_box(box1, box2)
+    pub fn merge(&mut self, other: &BoundingBox) {
+        if !other.is_empty() {
+            self.add_point(&other.min);
+            self.add_point(&other.max);
+        }
+    }
+
+    // In book: box_contains_point(box, point)
+    pub fn contains_point(&self, point: &Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+
+    // In book: box_contains_box(box, box2)
+    pub fn contains_box(&self, other: &BoundingBox) -> bool {
+        self.contains_point(&other.min) && self.contains_point(&other.max)
+    }
+
+    // In book: transform(box, matrix)
+    // The box around the transformed box. Rather than transforming all eight corners, each
+    // output axis takes the smaller and larger end of every input axis that feeds into it
+    // (Arvo's method), which also keeps infinite sides from turning into NaN where the matrix
+    // has a zero.
+    pub fn transform(&self, m: &Matrix4) -> BoundingBox {
+        if self.is_empty() {
+            return *self;
+        }
+        let min = [self.min.x, self.min.y, self.min.z];
+        let max = [self.max.x, self.max.y, self.max.z];
+        let mut new_min = [0.0; 3];
+        let mut new_max = [0.0; 3];
+        for row in 0..3 {
+            new_min[row] = m[(row, 3)];
+            new_max[row] = m[(row, 3)];
+            for col in 0..3 {
+                let scale = m[(row, col)];
+                if scale == 0.0 {
+                    continue;
+                }
+                let (a, b) = (scale * min[col], scale * max[col]);
+                new_min[row] += a.min(b);
+                new_max[row] += a.max(b);
+            }
+        }
+        BoundingBox::new(
+            Tuple::new_point(new_min[0], new_min[1], new_min[2]),
+            Tuple::new_point(new_max[0], new_max[1], new_max[2]),
+        )
+    }
+
+    // In book: intersects(box, ray)
+    // The same slab test as `Cube`, with the box's own sides
+    pub fn intersects(&self, ray: &Ray) -> bool {
+        self.entry_exit(ray).is_some()
+    }
+
+    // Where the ray (or the line it's on, since t can be negative) goes into and comes out of
+    // the box
+    pub fn entry_exit(&self, ray: &Ray) -> Option<(Float, Float)> {
+        if self.is_empty() {
+            return None;
+        }
+        let (xtmin, xtmax) = Self::check_axis(ray.origin.x, ray.direction.x, self.min.x, self.max.x);
+        let (ytmin, ytmax) = Self::check_axis(ray.origin.y, ray.direction.y, self.min.y, self.max.y);
+        let (ztmin, ztmax) = Self::check_axis(ray.origin.z, ray.direction.z, self.min.z, self.max.z);
+        let tmin = xtmin.max(ytmin).max(ztmin);
+        let tmax = xtmax.min(ytmax).min(ztmax);
+        if tmin <= tmax {
+            Some((tmin, tmax))
+        } else {
+            None
+        }
+    }
+
+    // The box made bigger by `amount` on every side
+    pub fn expand(&self, amount: Float) -> BoundingBox {
+        if self.is_empty() {
+            return *self;
+        }
+        let margin = Tuple::new_vector(amount, amount, amount);
+        BoundingBox::new(self.min - margin, self.max + margin)
+    }
+
+    fn check_axis(origin: Float, direction: Float, min: Float, max: Float) -> (Float, Float) {
+        let tmin_numerator = min - origin;
+        let tmax_numerator = max - origin;
+        let (tmin, tmax) = if direction.abs() >= EPSILON {
+            (tmin_numerator / direction, tmax_numerator / direction)
+        } else {
+            (tmin_numerator * Float::INFINITY, tmax_numerator * Float::INFINITY)
+        };
+        if tmin > tmax {
+            (tmax, tmin)
+        } else {
+            (tmin, tmax)
+        }
+    }
+
+    // In book: split_bounds(box)
+    // Cuts the box in half across its longest side. Halves of a box with an infinite side
+    // aren't good for anything (their middle is NaN), so nothing fits in them.
+    pub fn split(&self) -> (BoundingBox, BoundingBox) {
+        let dx = self.max.x - self.min.x;
+        let dy = self.max.y - self.min.y;
+        let dz = self.max.z - self.min.z;
+        let (mut mid_min, mut mid_max) = (self.min, self.max);
+        if dx >= dy && dx >= dz {
+            mid_min.x = self.min.x + dx / 2.0;
+            mid_max.x = mid_min.x;
+        } else if dy >= dz {
+            mid_min.y = self.min.y + dy / 2.0;
+            mid_max.y = mid_min.y;
+        } else {
+            mid_min.z = self.min.z + dz / 2.0;
+            mid_max.z = mid_min.z;
+        }
+        (BoundingBox::new(self.min, mid_max), BoundingBox::new(mid_min, self.max))
+    }
+}
@@ -0,0 +1,121 @@
+use std::{sync::atomic::{AtomicUsize, Ordering}, thread};
+
+use crate::{util::Float, matrix::NotInvertibleError, matrix4::Matrix4, ray::Ray, tuple::Tuple, canvas::Canvas, color::Color, world::World};
+
+// In book: camera(hsize, vsize, field_of_view)
+#[derive(Debug, Clone)]
+pub struct Camera {
+    hsize: usize,
+    vsize: usize,
+    field_of_view: Float,
+    transform: Matrix4,
+    inverse: Matrix4,
+    half_width: Float,
+    half_height: Float,
+    pixel_size: Float,
+    threads: usize,
+}
+
+impl Camera {
+    // pg 101
+    pub fn new(hsize: usize, vsize: usize, field_of_view: Float) -> Camera {
+        let half_view = (field_of_view / 2.0).tan();
+        let aspect = hsize as Float / vsize as Float;
+        let (half_width, half_height) = if aspect >= 1.0 {
+            (half_view, half_view / aspect)
+        } else {
+            (half_view * aspect, half_view)
+        };
+        let pixel_size = (half_width * 2.0) / hsize as Float;
+        Camera { hsize, vsize, field_of_view, transform: Matrix4::identity(), inverse: Matrix4::identity(), half_width, half_height, pixel_size, threads: Self::default_threads() }
+    }
+
+    pub fn hsize(&self) -> usize {
+        self.hsize
+    }
+
+    pub fn vsize(&self) -> usize {
+        self.vsize
+    }
+
+    pub fn field_of_view(&self) -> Float {
+        self.field_of_view
+    }
+
+    pub fn pixel_size(&self) -> Float {
+        self.pixel_size
+    }
+
+    // One render thread per CPU core, unless we can't tell how many there are
+    fn default_threads() -> usize {
+        thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    pub fn set_threads(&mut self, threads: usize) -> &Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    pub fn transformation(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    // The inverse is what every ray needs, so work it out once here instead of per pixel
+    pub fn set_transform(&mut self, trans: Matrix4) -> Result<(), NotInvertibleError> {
+        self.inverse = trans.inverse()?;
+        self.transform = trans;
+        Ok(())
+    }
+
+    // pg 103
+    // In book: ray_for_pixel(camera, px, py)
+    pub fn ray_for_pixel(&self, px: usize, py: usize) -> Ray {
+        // offset from the edge of the canvas to the pixel's center
+        let xoffset = (px as Float + 0.5) * self.pixel_size;
+        let yoffset = (py as Float + 0.5) * self.pixel_size;
+        // the camera looks toward -z, so +x is to the *left*
+        let world_x = self.half_width - xoffset;
+        let world_y = self.half_height - yoffset;
+        let pixel = self.inverse * Tuple::new_point(world_x, world_y, -1.0);
+        let origin = self.inverse * Tuple::origin_point();
+        let direction = (pixel - origin).normalize().unwrap();
+        Ray::new(origin, direction)
+    }
+
+    // pg 104
+    // In book: render(camera, world)
+    // Rows are handed out one at a time to each thread as it finishes its last one, so a
+    // thread that draws a lot of empty sky just ends up drawing more rows.
+    pub fn render(&self, world: &World) -> Canvas {
+        let next_row = AtomicUsize::new(0);
+        let rows: Vec<(usize, Vec<Color>)> = thread::scope(|scope| {
+            let workers: Vec<_> = (0..self.threads)
+                .map(|_| scope.spawn(|| {
+                    let mut done = vec![];
+                    loop {
+                        let y = next_row.fetch_add(1, Ordering::Relaxed);
+                        if y >= self.vsize {
+                            break done;
+                        }
+                        let row = (0..self.hsize)
+                            .map(|x| world.color_at(&self.ray_for_pixel(x, y)))
+                            .collect();
+                        done.push((y, row));
+                    }
+                }))
+                .collect();
+            workers.into_iter().flat_map(|w| w.join().unwrap()).collect()
+        });
+        let mut image = Canvas::new(self.hsize, self.vsize);
+        for (y, row) in rows {
+            for (x, color) in row.into_iter().enumerate() {
+                image[(x, y)] = color;
+            }
+        }
+        image
+    }
+}
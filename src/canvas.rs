@@ -14,7 +14,7 @@ pub struct Canvas {
 impl Canvas {
     pub fn new(width: usize, height: usize) -> Canvas {
         let pixels = Array2D::filled_with(BLACK, height, width);
-        Canvas { width, height, pixels: pixels }
+        Canvas { width, height, pixels }
     }
 
     fn scale_and_clamp_255(number: Float) -> u8 {
@@ -39,7 +39,7 @@ impl Canvas {
             v.push(strings.join(" "));
         }
         v.push(String::from("\n"));
-        return v;
+        v
     }
 
     pub fn write_ppm<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn Error>> {
@@ -64,7 +64,7 @@ impl Canvas {
             .as_row_major()
             .iter()
             .flat_map(|c| { 
-                [Self::scale_and_clamp_255(c.red), Self::scale_and_clamp_255(c.green), Self::scale_and_clamp_255(c.blue), 255 as u8]
+                [Self::scale_and_clamp_255(c.red), Self::scale_and_clamp_255(c.green), Self::scale_and_clamp_255(c.blue), 255_u8]
             })
             .collect();
         writer.write_image_data(&data)?;
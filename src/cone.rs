@@ -0,0 +1,121 @@
+use crate::{
+    bounds::BoundingBox,
+    ray::Ray,
+    shape::Shape,
+    tuple::{Point, Tuple, Vector},
+    util::{Float, EPSILON}, intersectable::Intersectable, intersection::Intersection,
+};
+
+// A double-napped cone around the y axis in object space: two cones meeting point to point at
+// the origin, with radius |y| at height y. Like `Cylinder` it can be cut off with
+// `minimum`/`maximum` and capped with `closed`; cut it at 0 to get a single cone.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Cone {
+    pub minimum: Float,
+    pub maximum: Float,
+    pub closed: bool,
+    shape: Shape,
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cone {
+    // In book: cone()
+    pub fn new() -> Cone {
+        Cone { minimum: Float::NEG_INFINITY, maximum: Float::INFINITY, closed: false, shape: Shape::new() }
+    }
+
+    pub fn new_truncated(minimum: Float, maximum: Float, closed: bool) -> Cone {
+        Cone { minimum, maximum, closed, ..Self::new() }
+    }
+
+    // Whether the ray is within the radius of the cone at t, which is on the cap at height y
+    fn check_cap(ray: &Ray, t: Float, y: Float) -> bool {
+        let x = ray.origin.x + t * ray.direction.x;
+        let z = ray.origin.z + t * ray.direction.z;
+        x.powi(2) + z.powi(2) <= y.powi(2)
+    }
+
+    // pg 190
+    fn intersect_caps<'a>(&'a self, ray: &Ray, xs: &mut Vec<Intersection<'a>>) {
+        if !self.closed || ray.direction.y.abs() < EPSILON {
+            return;
+        }
+        for cap in [self.minimum, self.maximum] {
+            let t = (cap - ray.origin.y) / ray.direction.y;
+            if Self::check_cap(ray, t, cap) {
+                xs.push(Intersection::new(t, self));
+            }
+        }
+    }
+
+    fn push_if_between<'a>(&'a self, ray: &Ray, t: Float, xs: &mut Vec<Intersection<'a>>) {
+        let y = ray.origin.y + t * ray.direction.y;
+        if self.minimum < y && y < self.maximum {
+            xs.push(Intersection::new(t, self));
+        }
+    }
+}
+
+impl Intersectable for Cone {
+    fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn mut_shape(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    // pg 189
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let mut xs = vec![];
+        let (o, d) = (&ray.origin, &ray.direction);
+        let a = d.x.powi(2) - d.y.powi(2) + d.z.powi(2);
+        let b = 2.0 * o.x * d.x - 2.0 * o.y * d.y + 2.0 * o.z * d.z;
+        let c = o.x.powi(2) - o.y.powi(2) + o.z.powi(2);
+        if a.abs() < EPSILON {
+            // parallel to one of the halves, so it can only cross the other one
+            if b.abs() >= EPSILON {
+                self.push_if_between(ray, -c / (2.0 * b), &mut xs);
+            }
+        } else {
+            let disc = b.powi(2) - 4.0 * a * c;
+            if disc >= 0.0 {
+                let t0 = (-b - disc.sqrt()) / (2.0 * a);
+                let t1 = (-b + disc.sqrt()) / (2.0 * a);
+                self.push_if_between(ray, t0.min(t1), &mut xs);
+                self.push_if_between(ray, t0.max(t1), &mut xs);
+            }
+        }
+        self.intersect_caps(ray, &mut xs);
+        xs
+    }
+
+    // pg 191
+    fn local_normal_at(&self, point: &Point) -> Vector {
+        let dist = point.x.powi(2) + point.z.powi(2);
+        if dist < self.maximum.powi(2) && point.y >= self.maximum - EPSILON {
+            Tuple::new_vector(0.0, 1.0, 0.0)
+        } else if dist < self.minimum.powi(2) && point.y <= self.minimum + EPSILON {
+            Tuple::new_vector(0.0, -1.0, 0.0)
+        } else {
+            let y = dist.sqrt();
+            let y = if point.y > 0.0 { -y } else { y };
+            Tuple::new_vector(point.x, y, point.z)
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    // the radius at any height is the distance from the tip, so the widest end sets the box
+    fn bounds(&self) -> BoundingBox {
+        let limit = self.minimum.abs().max(self.maximum.abs());
+        BoundingBox::new(Tuple::new_point(-limit, self.minimum, -limit), Tuple::new_point(limit, self.maximum, limit))
+    }
+}
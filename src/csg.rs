@@ -0,0 +1,153 @@
+use crate::{
+    bounds::BoundingBox,
+    ray::Ray,
+    shape::Shape,
+    matrix::NotInvertibleError,
+    matrix4::Matrix4,
+    tuple::{Point, Vector},
+    intersectable::Intersectable, intersection::Intersection,
+};
+
+// How a `Csg` shape combines its two sides
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CsgOperation {
+    // everything in either side
+    Union,
+    // only where the sides overlap
+    Intersection,
+    // the left side with the right side cut out of it
+    Difference,
+}
+
+impl CsgOperation {
+    // pg 234
+    // In book: intersection_allowed(op, lhit, inl, inr)
+    // Whether a hit belongs on the combined surface: `left_hit` says which side was hit, and
+    // `in_left`/`in_right` whether the ray was inside each side at the time
+    pub fn intersection_allowed(&self, left_hit: bool, in_left: bool, in_right: bool) -> bool {
+        match self {
+            CsgOperation::Union => (left_hit && !in_right) || (!left_hit && !in_left),
+            CsgOperation::Intersection => (left_hit && in_right) || (!left_hit && in_left),
+            CsgOperation::Difference => (left_hit && !in_right) || (!left_hit && in_left),
+        }
+    }
+}
+
+// pg 230
+// In book: csg(operation, left, right)
+// Constructive solid geometry: two shapes (either of which can be a group or another CSG)
+// combined into one. Like a group, the CSG's transform applies to both sides, and hits are
+// always on one of the shapes inside it.
+#[derive(Debug)]
+pub struct Csg {
+    shape: Shape,
+    operation: CsgOperation,
+    left: Box<dyn Intersectable>,
+    right: Box<dyn Intersectable>,
+}
+
+impl Csg {
+    pub fn new(operation: CsgOperation, mut left: Box<dyn Intersectable>, mut right: Box<dyn Intersectable>) -> Csg {
+        let shape = Shape::new();
+        left.set_parent_inverse(*shape.world_inverse());
+        right.set_parent_inverse(*shape.world_inverse());
+        Csg { shape, operation, left, right }
+    }
+
+    pub fn operation(&self) -> CsgOperation {
+        self.operation
+    }
+
+    pub fn left(&self) -> &dyn Intersectable {
+        self.left.as_ref()
+    }
+
+    pub fn right(&self) -> &dyn Intersectable {
+        self.right.as_ref()
+    }
+
+    // pg 235
+    // In book: filter_intersections(csg, xs)
+    // Walks along the sorted hits keeping track of whether the ray is inside each side, and
+    // keeps the ones the operation allows
+    pub fn filter_intersections<'a>(&self, intersections: Vec<Intersection<'a>>) -> Vec<Intersection<'a>> {
+        let (mut in_left, mut in_right) = (false, false);
+        let mut result = vec![];
+        for i in intersections {
+            let left_hit = self.left.includes(i.object);
+            if self.operation.intersection_allowed(left_hit, in_left, in_right) {
+                result.push(i);
+            }
+            if left_hit {
+                in_left = !in_left;
+            } else {
+                in_right = !in_right;
+            }
+        }
+        result
+    }
+
+    fn update_children(&mut self) {
+        let world_inverse = *self.shape.world_inverse();
+        self.left.set_parent_inverse(world_inverse);
+        self.right.set_parent_inverse(world_inverse);
+    }
+}
+
+impl Intersectable for Csg {
+    fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn mut_shape(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    // pg 236
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        if !self.bounds().intersects(ray) {
+            return vec![];
+        }
+        let mut xs = self.left.intersections(ray);
+        xs.extend(self.right.intersections(ray));
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        self.filter_intersections(xs)
+    }
+
+    // hits are always on the left or right shape, which have normals of their own
+    fn local_normal_at(&self, _point: &Point) -> Vector {
+        panic!("a CSG shape has no normal of its own; ask the shape inside it that was hit")
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    // Both sides, whatever the operation: a difference or intersection could have a smaller
+    // box, but this one is never wrong
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = self.left.parent_space_bounds();
+        bounds.merge(&self.right.parent_space_bounds());
+        bounds
+    }
+
+    fn divide(&mut self, threshold: usize) {
+        self.left.divide(threshold);
+        self.right.divide(threshold);
+    }
+
+    fn includes(&self, other: &dyn Intersectable) -> bool {
+        self.left.includes(other) || self.right.includes(other)
+    }
+
+    fn set_transform(&mut self, trans: Matrix4) -> Result<(), NotInvertibleError> {
+        self.shape.set_transform(trans)?;
+        self.update_children();
+        Ok(())
+    }
+
+    fn set_parent_inverse(&mut self, parent_inverse: Matrix4) {
+        self.shape.set_parent_inverse(parent_inverse);
+        self.update_children();
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    bounds::BoundingBox,
+    ray::Ray,
+    shape::Shape,
+    tuple::{Point, Tuple, Vector},
+    util::{Float, EPSILON}, intersectable::Intersectable, intersection::Intersection,
+};
+
+// An axis-aligned box. In object space it runs from -1 to 1 on every axis
+#[derive(Debug, PartialEq, Clone)]
+pub struct Cube {
+    shape: Shape,
+}
+
+impl Default for Cube {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cube {
+    // In book: cube()
+    pub fn new() -> Cube {
+        Cube { shape: Shape::new() }
+    }
+
+    // pg 170
+    // In book: check_axis(origin, direction)
+    // Where the ray crosses the two sides of the cube facing along one axis, nearest first
+    fn check_axis(origin: Float, direction: Float) -> (Float, Float) {
+        let tmin_numerator = -1.0 - origin;
+        let tmax_numerator = 1.0 - origin;
+        let (tmin, tmax) = if direction.abs() >= EPSILON {
+            (tmin_numerator / direction, tmax_numerator / direction)
+        } else {
+            // parallel to these sides: the ray is either always between them or never
+            (tmin_numerator * Float::INFINITY, tmax_numerator * Float::INFINITY)
+        };
+        if tmin > tmax {
+            (tmax, tmin)
+        } else {
+            (tmin, tmax)
+        }
+    }
+}
+
+impl Intersectable for Cube {
+    fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn mut_shape(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    // pg 169
+    // The ray is inside the cube between where it has crossed into all three pairs of sides
+    // and where it crosses out of the first one
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let (xtmin, xtmax) = Self::check_axis(ray.origin.x, ray.direction.x);
+        let (ytmin, ytmax) = Self::check_axis(ray.origin.y, ray.direction.y);
+        let (ztmin, ztmax) = Self::check_axis(ray.origin.z, ray.direction.z);
+        let tmin = xtmin.max(ytmin).max(ztmin);
+        let tmax = xtmax.min(ytmax).min(ztmax);
+        if tmin > tmax {
+            return vec![];
+        }
+        vec![Intersection::new(tmin, self), Intersection::new(tmax, self)]
+    }
+
+    // pg 173: the face the point is on is the one along its largest component
+    fn local_normal_at(&self, point: &Point) -> Vector {
+        let (x, y, z) = (point.x.abs(), point.y.abs(), point.z.abs());
+        let maxc = x.max(y).max(z);
+        if maxc == x {
+            Tuple::new_vector(point.x, 0.0, 0.0)
+        } else if maxc == y {
+            Tuple::new_vector(0.0, point.y, 0.0)
+        } else {
+            Tuple::new_vector(0.0, 0.0, point.z)
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(Tuple::new_point(-1.0, -1.0, -1.0), Tuple::new_point(1.0, 1.0, 1.0))
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    bounds::BoundingBox,
+    ray::Ray,
+    shape::Shape,
+    tuple::{Point, Tuple, Vector},
+    util::{Float, EPSILON}, intersectable::Intersectable, intersection::Intersection,
+};
+
+// A cylinder of radius 1 around the y axis in object space. It's infinitely long unless
+// `minimum`/`maximum` cut it off (those ends aren't included), and a `closed` cylinder has
+// caps on the ends.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Cylinder {
+    pub minimum: Float,
+    pub maximum: Float,
+    pub closed: bool,
+    shape: Shape,
+}
+
+impl Default for Cylinder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cylinder {
+    // In book: cylinder()
+    pub fn new() -> Cylinder {
+        Cylinder { minimum: Float::NEG_INFINITY, maximum: Float::INFINITY, closed: false, shape: Shape::new() }
+    }
+
+    pub fn new_truncated(minimum: Float, maximum: Float, closed: bool) -> Cylinder {
+        Cylinder { minimum, maximum, closed, ..Self::new() }
+    }
+
+    // pg 185
+    // In book: check_cap(ray, t)
+    // Whether the ray is within the radius of the cylinder at t
+    fn check_cap(ray: &Ray, t: Float) -> bool {
+        let x = ray.origin.x + t * ray.direction.x;
+        let z = ray.origin.z + t * ray.direction.z;
+        x.powi(2) + z.powi(2) <= 1.0
+    }
+
+    // pg 185
+    // In book: intersect_caps(cyl, ray, xs)
+    fn intersect_caps<'a>(&'a self, ray: &Ray, xs: &mut Vec<Intersection<'a>>) {
+        // caps only matter if the cylinder is closed and the ray isn't running parallel to them
+        if !self.closed || ray.direction.y.abs() < EPSILON {
+            return;
+        }
+        for cap in [self.minimum, self.maximum] {
+            let t = (cap - ray.origin.y) / ray.direction.y;
+            if Self::check_cap(ray, t) {
+                xs.push(Intersection::new(t, self));
+            }
+        }
+    }
+}
+
+impl Intersectable for Cylinder {
+    fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn mut_shape(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    // pg 178
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let mut xs = vec![];
+        let a = ray.direction.x.powi(2) + ray.direction.z.powi(2);
+        // a ray parallel to the y axis can only hit the caps
+        if a.abs() >= EPSILON {
+            let b = 2.0 * ray.origin.x * ray.direction.x + 2.0 * ray.origin.z * ray.direction.z;
+            let c = ray.origin.x.powi(2) + ray.origin.z.powi(2) - 1.0;
+            let disc = b.powi(2) - 4.0 * a * c;
+            if disc < 0.0 {
+                return xs;
+            }
+            let t0 = (-b - disc.sqrt()) / (2.0 * a);
+            let t1 = (-b + disc.sqrt()) / (2.0 * a);
+            // pg 182: only keep the parts of the wall between the ends
+            for t in [t0.min(t1), t0.max(t1)] {
+                let y = ray.origin.y + t * ray.direction.y;
+                if self.minimum < y && y < self.maximum {
+                    xs.push(Intersection::new(t, self));
+                }
+            }
+        }
+        self.intersect_caps(ray, &mut xs);
+        xs
+    }
+
+    // pg 181 and pg 187 for the caps
+    fn local_normal_at(&self, point: &Point) -> Vector {
+        let dist = point.x.powi(2) + point.z.powi(2);
+        if dist < 1.0 && point.y >= self.maximum - EPSILON {
+            Tuple::new_vector(0.0, 1.0, 0.0)
+        } else if dist < 1.0 && point.y <= self.minimum + EPSILON {
+            Tuple::new_vector(0.0, -1.0, 0.0)
+        } else {
+            Tuple::new_vector(point.x, 0.0, point.z)
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(Tuple::new_point(-1.0, self.minimum, -1.0), Tuple::new_point(1.0, self.maximum, 1.0))
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{
+    bounds::BoundingBox,
+    ray::Ray,
+    shape::Shape,
+    matrix::NotInvertibleError,
+    matrix4::Matrix4,
+    tuple::{Point, Vector},
+    intersectable::Intersectable, intersection::Intersection,
+};
+
+// pg 195
+// A collection of shapes that are moved around as one. The group's transform applies to every
+// child on top of the child's own transform; groups can hold other groups.
+// Rays that hit a group hit one of its children, so intersections always refer to the child
+// and a group never needs a normal or a material of its own.
+// The box around the children is kept up to date as they're added, so rays that miss it can
+// skip all of them; `divide` sorts a big group into a hierarchy of smaller boxes.
+#[derive(Debug, Default)]
+pub struct Group {
+    shape: Shape,
+    children: Children,
+    bounds: BoundingBox,
+}
+
+type Children = Vec<Box<dyn Intersectable>>;
+
+// How many children a group can have before `ObjFile` meshes in a scene get divided
+pub const DEFAULT_DIVIDE_THRESHOLD: usize = 8;
+
+impl Group {
+    // In book: group()
+    pub fn new() -> Group {
+        Group::default()
+    }
+
+    pub fn children(&self) -> &[Box<dyn Intersectable>] {
+        &self.children
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    // pg 195
+    // In book: add_child(group, shape)
+    pub fn add_child(&mut self, mut child: Box<dyn Intersectable>) {
+        child.set_parent_inverse(*self.shape.world_inverse());
+        self.bounds.merge(&child.parent_space_bounds());
+        self.children.push(child);
+    }
+
+    // In book: partition_children(group)
+    // Takes out the children that fit entirely in one half of the group's box, returning them
+    // as (left, right). Children that straddle the middle stay where they are.
+    pub fn partition_children(&mut self) -> (Children, Children) {
+        let (left_box, right_box) = self.bounds.split();
+        let (mut left, mut right, mut kept) = (vec![], vec![], vec![]);
+        for child in self.children.drain(..) {
+            let bounds = child.parent_space_bounds();
+            if left_box.contains_box(&bounds) {
+                left.push(child);
+            } else if right_box.contains_box(&bounds) {
+                right.push(child);
+            } else {
+                kept.push(child);
+            }
+        }
+        self.children = kept;
+        (left, right)
+    }
+
+    // In book: make_subgroup(group, shapes)
+    fn make_subgroup(&mut self, shapes: Children) {
+        let mut subgroup = Group::new();
+        for shape in shapes {
+            subgroup.add_child(shape);
+        }
+        self.add_child(Box::new(subgroup));
+    }
+
+    fn update_children(&mut self) {
+        let world_inverse = *self.shape.world_inverse();
+        for child in self.children.iter_mut() {
+            child.set_parent_inverse(world_inverse);
+        }
+    }
+}
+
+impl Intersectable for Group {
+    fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn mut_shape(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    // pg 196
+    // The ray is already in the group's space, which is where the children's transforms start from
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        if !self.bounds.intersects(ray) {
+            return vec![];
+        }
+        let mut xs: Vec<Intersection> = self.children.iter().flat_map(|child| child.intersections(ray)).collect();
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        xs
+    }
+
+    // pg 199: intersections are always with a child, so nothing should ask a group for a normal
+    fn local_normal_at(&self, _point: &Point) -> Vector {
+        panic!("a group has no normal of its own; ask the child that was hit")
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.bounds
+    }
+
+    // Splits any group with at least `threshold` children, then does the same inside every
+    // child. A half that would get every child (which only happens when the box has no size to
+    // split) is left alone, or this would never stop.
+    fn divide(&mut self, threshold: usize) {
+        if self.children.len() >= threshold {
+            let count = self.children.len();
+            let (left, right) = self.partition_children();
+            for half in [left, right] {
+                if half.len() == count {
+                    for child in half {
+                        self.add_child(child);
+                    }
+                } else if !half.is_empty() {
+                    self.make_subgroup(half);
+                }
+            }
+        }
+        for child in self.children.iter_mut() {
+            child.divide(threshold);
+        }
+    }
+
+    fn includes(&self, other: &dyn Intersectable) -> bool {
+        self.children.iter().any(|child| child.includes(other))
+    }
+
+    fn set_transform(&mut self, trans: Matrix4) -> Result<(), NotInvertibleError> {
+        self.shape.set_transform(trans)?;
+        self.update_children();
+        Ok(())
+    }
+
+    fn set_parent_inverse(&mut self, parent_inverse: Matrix4) {
+        self.shape.set_parent_inverse(parent_inverse);
+        self.update_children();
+    }
+}
@@ -1,16 +1,107 @@
 use std::any::Any;
 
-use crate::{ray::Ray, intersection::Intersection, matrix::Matrix, material::Material, tuple::{Point, Vector}};
+use crate::{bounds::BoundingBox, ray::Ray, intersection::Intersection, matrix::NotInvertibleError, matrix4::Matrix4, material::Material, shape::Shape, tuple::{Point, Vector}};
 
-pub trait Intersectable: std::fmt::Debug + Any {
-    fn is_intersecting(&self, ray: &Ray) -> bool;
-    fn intersection_count(&self, ray: &Ray) -> usize;
-    fn intersections(&self, ray: &Ray) -> Vec<Intersection>;
+// Every shape implements the geometry in its own object space (`local_intersect` and
+// `local_normal_at`); the default methods handle moving rays and normals between
+// world space and object space using the transform stored in the shape's `Shape`.
+// Rays reach shapes inside a `Group` already in the group's space, so `intersections` only
+// applies the shape's own transform, while normals go all the way back to world space.
+// Shapes are shared between render threads, hence `Send + Sync`.
+pub trait Intersectable: std::fmt::Debug + Any + Send + Sync {
+    fn shape(&self) -> &Shape;
+    fn mut_shape(&mut self) -> &mut Shape;
+    // In book: local_intersect(shape, local_ray)
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>>;
+    // In book: local_normal_at(shape, local_point)
+    fn local_normal_at(&self, point: &Point) -> Vector;
+    // In book: local_normal_at(shape, local_point, hit)
+    // Only shapes that need to know where they were hit (smooth triangles) override this
+    fn local_normal_at_hit(&self, point: &Point, _hit: &Intersection) -> Vector {
+        self.local_normal_at(point)
+    }
     fn as_any(&self) -> &dyn Any;
-    fn transformation(&self) -> &Matrix;
-    fn surface_normal(&self, point: &Point) -> Vector;
-    fn mut_material(&mut self) -> &mut Material;
-    fn material(&self) -> &Material;
+    // In book: bounds_of(shape)
+    // The box around the shape in its own object space
+    fn bounds(&self) -> BoundingBox;
+
+    // In book: parent_space_bounds_of(shape)
+    fn parent_space_bounds(&self) -> BoundingBox {
+        self.bounds().transform(self.transformation())
+    }
+
+    // In book: divide(shape, threshold)
+    // Only groups (and the groups inside CSG shapes) have anything to divide
+    fn divide(&mut self, _threshold: usize) {}
+
+    // In book: includes(a, b)
+    // Whether `other` is this very shape; groups and CSG shapes also look inside themselves
+    fn includes(&self, other: &dyn Intersectable) -> bool {
+        std::ptr::addr_eq(self as *const Self, other as *const dyn Intersectable)
+    }
+
+    fn is_intersecting(&self, ray: &Ray) -> bool {
+        !self.intersections(ray).is_empty()
+    }
+
+    fn intersection_count(&self, ray: &Ray) -> usize {
+        self.intersections(ray).len()
+    }
+
+    // pg 119
+    // In book: intersect(shape, ray)
+    fn intersections(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        self.local_intersect(&(self.shape().inverse() * ray))
+    }
+
+    fn transformation(&self) -> &Matrix4 {
+        self.shape().transformation()
+    }
+
+    fn set_transform(&mut self, trans: Matrix4) -> Result<(), NotInvertibleError> {
+        self.mut_shape().set_transform(trans)
+    }
+
+    // Called by the group a shape is added to (and again whenever that group moves); groups
+    // override this to pass it on to their own children
+    fn set_parent_inverse(&mut self, parent_inverse: Matrix4) {
+        self.mut_shape().set_parent_inverse(parent_inverse);
+    }
+
+    // pg 197
+    // In book: world_to_object(shape, point)
+    fn world_to_object(&self, point: &Point) -> Point {
+        self.shape().world_inverse() * point
+    }
+
+    // pg 120
+    // In book: normal_at(shape, point)
+    fn surface_normal(&self, point: &Point) -> Vector {
+        let local_point = self.world_to_object(point);
+        self.normal_to_world(&self.local_normal_at(&local_point))
+    }
+
+    // pg 222
+    // In book: normal_at(shape, point, hit)
+    fn surface_normal_at_hit(&self, point: &Point, hit: &Intersection) -> Vector {
+        let local_point = self.world_to_object(point);
+        self.normal_to_world(&self.local_normal_at_hit(&local_point, hit))
+    }
+
+    // pg 198
+    // In book: normal_to_world(shape, normal)
+    fn normal_to_world(&self, local_normal: &Vector) -> Vector {
+        let mut world_normal = self.shape().world_inverse_transpose() * local_normal;
+        world_normal.w = 0.0;
+        world_normal.normalize().unwrap()
+    }
+
+    fn mut_material(&mut self) -> &mut Material {
+        &mut self.mut_shape().material
+    }
+    fn material(&self) -> &Material {
+        &self.shape().material
+    }
 }
 
 impl<T: PartialEq + Any> PartialEq<T> for dyn Intersectable {
@@ -21,4 +112,4 @@ impl<T: PartialEq + Any> PartialEq<T> for dyn Intersectable {
             false
         }
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,4 @@
-use crate::{util::Float, tuple::{Point, Vector}, intersectable::Intersectable, ray::Ray};
+use crate::{util::{Float, EPSILON}, tuple::{Point, Vector}, intersectable::Intersectable, ray::Ray};
 
 #[derive(Debug, Clone)]
 pub struct IntersectionPrecomputation<'a> {
@@ -8,39 +8,115 @@ pub struct IntersectionPrecomputation<'a> {
     pub eyev: Vector,
     pub normalv: Vector,
     pub inside: bool,
+    pub over_point: Point,
+    pub reflectv: Vector,
+    // refractive indices on the side the ray is coming from (n1) and the side it's going into (n2)
+    pub n1: Float,
+    pub n2: Float,
+    // just below the surface, where refracted rays start
+    pub under_point: Point,
+}
+
+impl IntersectionPrecomputation<'_> {
+    // pg 161
+    // In book: schlick(comps)
+    // How much of the light is reflected rather than refracted
+    pub fn schlick(&self) -> Float {
+        let mut cos = self.eyev.dot(&self.normalv);
+        if self.n1 > self.n2 {
+            let n = self.n1 / self.n2;
+            let sin2_t = n.powi(2) * (1.0 - cos.powi(2));
+            if sin2_t > 1.0 {
+                return 1.0; // total internal reflection
+            }
+            cos = (1.0 - sin2_t).sqrt();
+        }
+        let r0 = ((self.n1 - self.n2) / (self.n1 + self.n2)).powi(2);
+        r0 + (1.0 - r0) * (1.0 - cos).powi(5)
+    }
 }
 
-// In book: intersection(int, object)
 #[derive(Debug, Clone)]
 pub struct Intersection<'a> {
     pub t: Float,
     pub object:  &'a dyn Intersectable,
+    // Where on the surface the hit was, for shapes that need it (triangles)
+    pub uv: Option<(Float, Float)>,
 }
 
 impl <'a> Intersection<'a> {
+    // In book: intersection(t, object)
+    pub fn new(t: Float, object: &'a dyn Intersectable) -> Intersection<'a> {
+        Intersection { t, object, uv: None }
+    }
+
+    // pg 221
+    // In book: intersection_with_uv(t, object, u, v)
+    pub fn new_with_uv(t: Float, object: &'a dyn Intersectable, u: Float, v: Float) -> Intersection<'a> {
+        Intersection { t, object, uv: Some((u, v)) }
+    }
+
     // In book: hit(xs)
     pub fn hit(intersections: &Vec<Intersection<'a>>) -> Option<Intersection<'a>> {
         intersections.iter()
         .filter(|i| i.t >= 0.0)
         .reduce(|acc, e| if e < acc { e } else { acc })
-        .map(|i| i.clone())
+        .cloned()
     }
 
     // In book: prepare_computations(int, ray)
-    pub fn precompute(&self, ray: &Ray) -> IntersectionPrecomputation {
+    // Treats this as the only intersection, so the ray starts and ends in a vacuum
+    pub fn precompute(&self, ray: &Ray) -> IntersectionPrecomputation<'_> {
+        self.precompute_with_intersections(ray, std::slice::from_ref(self))
+    }
+
+    // pg 152
+    // In book: prepare_computations(int, ray, xs)
+    // `intersections` must be sorted, like `World::intersections` returns them
+    pub fn precompute_with_intersections(&self, ray: &Ray, intersections: &[Intersection<'a>]) -> IntersectionPrecomputation<'_> {
         let t = self.t;
         let object = self.object;
         let point = ray.position(&self.t);
         let eyev = -ray.direction;
-        let normalv = self.object.surface_normal(&point);
+        let normalv = self.object.surface_normal_at_hit(&point, self);
         let inside = normalv.dot(&eyev) < 0.0;
         let normalv = if inside {
             -normalv
         } else {
             normalv
         };
-        IntersectionPrecomputation { t, object, point, eyev, normalv, inside }
+        // pg 115: bump the point slightly above the surface to avoid shadow acne
+        let over_point = point + normalv * EPSILON;
+        // pg 143
+        let reflectv = ray.direction.reflect(&normalv).unwrap();
+        // pg 154: refracted rays start just below the surface instead
+        let under_point = point - normalv * EPSILON;
+        let (n1, n2) = self.refractive_indices(intersections);
+        IntersectionPrecomputation { t, object, point, eyev, normalv, inside, over_point, reflectv, n1, n2, under_point }
     }
+
+    // Walk the hits up to this one, tracking which objects the ray is inside of
+    fn refractive_indices(&self, intersections: &[Intersection<'a>]) -> (Float, Float) {
+        let index = |containers: &Vec<&dyn Intersectable>| {
+            containers.last().map_or(1.0, |o| o.material().refractive_index)
+        };
+        let mut containers: Vec<&dyn Intersectable> = Vec::new();
+        for i in intersections {
+            let n1 = index(&containers);
+            match containers.iter().position(|o| same_object(*o, i.object)) {
+                Some(p) => { containers.remove(p); },
+                None => containers.push(i.object),
+            }
+            if i == self {
+                return (n1, index(&containers));
+            }
+        }
+        (1.0, 1.0)
+    }
+}
+
+fn same_object(a: &dyn Intersectable, b: &dyn Intersectable) -> bool {
+    a as *const dyn Intersectable as *const () == b as *const dyn Intersectable as *const ()
 }
 
 impl PartialOrd for Intersection<'_> {
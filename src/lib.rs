@@ -3,10 +3,30 @@ pub mod tuple;
 pub mod color;
 pub mod canvas;
 pub mod matrix;
+pub mod matrix4;
 pub mod ray;
 pub mod sphere;
+pub mod plane;
+pub mod cube;
+pub mod cylinder;
+pub mod cone;
+pub mod torus;
+pub mod quadric;
+pub mod sdf;
+pub mod triangle;
+pub mod smooth_triangle;
+pub mod group;
+pub mod csg;
+pub mod bounds;
+pub mod obj;
 pub mod light;
 pub mod material;
+pub mod pattern;
+pub mod perlin;
+pub mod polynomial;
 pub mod world;
 pub mod intersection;
-pub mod intersectable;
\ No newline at end of file
+pub mod intersectable;
+pub mod shape;
+pub mod camera;
+pub mod scene;
\ No newline at end of file
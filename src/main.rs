@@ -1,5 +1,3 @@
-mod util;
-mod tuple;
 use std::error::Error;
 
 use book_renderer::{canvas::Canvas, color::Color};
@@ -1,26 +1,72 @@
-use crate::{color::{Color, BLACK}, util::Float, light::Light, tuple::{Point, Vector}};
+use std::sync::Arc;
 
-#[derive(Debug, PartialEq, PartialOrd, Clone)]
+use crate::{color::{Color, BLACK}, util::Float, light::Light, tuple::{Point, Vector}, pattern::Pattern, intersectable::Intersectable};
+
+#[derive(Debug, Clone)]
 pub struct Material {
     pub color: Color,
+    // When there's a pattern it's used instead of `color`
+    pub pattern: Option<Arc<dyn Pattern>>,
     pub ambient: Float,
     pub diffuse: Float,
     pub specular: Float,
     pub shininess: Float,
+    pub reflective: Float,
+    pub transparency: Float,
+    pub refractive_index: Float,
+}
+
+impl Default for Material {
+    fn default() -> Material {
+        Material{ color: Color::new(1.0, 1.0, 1.0), pattern: None, ambient: 0.1, diffuse: 0.9, specular: 0.9, shininess: 200.0, reflective: 0.0, transparency: 0.0, refractive_index: 1.0 }
+    }
+}
+
+// Patterns can't be compared, so two materials only match if they share the same pattern
+impl PartialEq for Material {
+    fn eq(&self, other: &Self) -> bool {
+        let same_pattern = match (&self.pattern, &other.pattern) {
+            (None, None) => true,
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        };
+        same_pattern
+            && self.color == other.color
+            && self.ambient == other.ambient
+            && self.diffuse == other.diffuse
+            && self.specular == other.specular
+            && self.shininess == other.shininess
+            && self.reflective == other.reflective
+            && self.transparency == other.transparency
+            && self.refractive_index == other.refractive_index
+    }
 }
 
 impl Material {
-    pub fn default() -> Material {
-        Material{ color: Color::new(1.0, 1.0, 1.0), ambient: 0.1, diffuse: 0.9, specular: 0.9, shininess: 200.0 }
+    pub fn set_pattern<P: Pattern + 'static>(&mut self, pattern: P) -> &Self {
+        self.pattern = Some(Arc::new(pattern));
+        self
     }
 
-    pub fn light(&self, light: &Light, position: &Point, eyev: &Vector, normalv: &Vector) -> Color {
-        let effective_color = self.color * light.intensity; // combine object and light colors
+    // The color of the material at a point on the object
+    pub fn color_at(&self, object: &dyn Intersectable, position: &Point) -> Color {
+        match &self.pattern {
+            Some(pattern) => pattern.color_at_object(object, position),
+            None => self.color,
+        }
+    }
+
+    // In book: lighting(material, object, light, point, eyev, normalv, in_shadow)
+    pub fn light(&self, object: &dyn Intersectable, light: &Light, position: &Point, eyev: &Vector, normalv: &Vector, in_shadow: bool) -> Color {
+        let effective_color = self.color_at(object, position) * light.intensity; // combine object and light colors
         let lightv = (light.position - position).normalize().unwrap();
         let ambient = effective_color * self.ambient;
+        if in_shadow {
+            return ambient;
+        }
         let light_dot_normal = lightv.dot(normalv);
-        let mut diffuse = BLACK.clone();
-        let mut specular = BLACK.clone();
+        let mut diffuse = BLACK;
+        let mut specular = BLACK;
         if light_dot_normal >= 0.0 {
             diffuse = effective_color * self.diffuse * light_dot_normal;
             let reflectv = (-lightv).reflect(normalv).unwrap();
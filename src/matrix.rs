@@ -2,7 +2,7 @@ use std::{ops::{IndexMut, Index}, error::Error, num::ParseFloatError};
 use auto_ops::impl_op_ex;
 use regex::Regex;
 use array2d::Array2D;
-use crate::{tuple::{Tuple, Point, Vector}, util::{feq, Float}};
+use crate::{tuple::Tuple, util::{feq, Float}};
 
 #[derive(Debug)]
 pub struct NotInvertibleError {}
@@ -37,7 +37,7 @@ impl Matrix {
         let mut array = Array2D::filled_with(0.0,height,width);
         for (r,line) in contents.split('\n').map(|l| l.trim()).filter(|l| ! l.is_empty()).enumerate() {
             for (c,n) in re.split(line).map(|w| w.trim()).filter(|w| ! w.is_empty()).enumerate() {
-                array[(r,c)] = n.trim().parse::<Float>().or_else(|e| Err(MatrixParseError{token: n.to_string(), err: e}))?;
+                array[(r,c)] = n.trim().parse::<Float>().map_err(|e| MatrixParseError{token: n.to_string(), err: e})?;
             }
         }
         Ok(Matrix{n:array})
@@ -49,79 +49,12 @@ impl Matrix {
         }
         m
     }
-    pub fn translation(x: Float, y: Float, z: Float) -> Self {
-        let mut m = Self::identity(4);
-        m[(0,3)] = x;
-        m[(1,3)] = y;
-        m[(2,3)] = z;
-        m
-    }
-    pub fn scaling(x: Float, y: Float, z: Float) -> Self {
-        let mut m = Self::identity(4);
-        m[(0,0)] = x;
-        m[(1,1)] = y;
-        m[(2,2)] = z;
-        m
-    }
-    pub fn rotation_x(radians: Float) -> Self {
-        let mut m = Self::identity(4);
-        m[(1,1)] = radians.cos();
-        m[(2,1)] = radians.sin();
-        m[(1,2)] = -m[(2,1)];
-        m[(2,2)] = m[(1,1)];
-        m
-    }
-    pub fn rotation_y(radians: Float) -> Self {
-        let mut m = Self::identity(4);
-        m[(0,0)] = radians.cos();
-        m[(0,2)] = radians.sin();
-        m[(2,0)] = -m[(0,2)];
-        m[(2,2)] = m[(0,0)];
-        m
-    }
-    pub fn rotation_z(radians: Float) -> Self {
-        let mut m = Self::identity(4);
-        m[(0,0)] = radians.cos();
-        m[(1,0)] = radians.sin();
-        m[(0,1)] = -m[(1,0)];
-        m[(1,1)] = m[(0,0)];
-        m
-    }
-    pub fn shear(xy: Float, xz: Float, yx: Float, yz: Float, zx: Float, zy: Float) -> Self {
-        let mut m = Self::identity(4);
-        m[(0,1)] = xy;
-        m[(0,2)] = xz;
-        m[(1,0)] = yx;
-        m[(1,2)] = yz;
-        m[(2,0)] = zx;
-        m[(2,1)] = zy;
-        m
-    }
-
-    // page 100
-    // In book: view_transform(from, to, up)
-    pub fn view_transform(from: &Point, to: &Point, up: &Vector) -> Result<Self,Box<dyn Error>> {
-        let forward = (to - from).normalize()?;
-        let left = forward.cross(&up.normalize()?)?;
-        let up = left.cross(&forward)?;
-        let mut orientation = Matrix::identity(4);
-        orientation[(0,0)] = left.x;
-        orientation[(0,1)] = left.y;
-        orientation[(0,2)] = left.z;
-        orientation[(1,0)] = up.x;
-        orientation[(1,1)] = up.y;
-        orientation[(1,2)] = up.z;
-        orientation[(2,0)] = -forward.x;
-        orientation[(2,1)] = -forward.y;
-        orientation[(2,2)] = -forward.z;
-        Ok(orientation * Self::translation(-from.x, -from.y, -from.z))
-    }
 
     pub fn is_square(&self) -> bool {
         self.height() == self.width()
     }
 
-    fn assert_square(&self) -> () {
+    fn assert_square(&self) {
         if ! self.is_square() {
             panic!("Matrix is not square: {} x {}", self.n.num_columns(), self.n.num_rows());
         }
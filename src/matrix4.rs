@@ -0,0 +1,281 @@
+use std::{ops::{Index, IndexMut}, error::Error, str::FromStr};
+use auto_ops::impl_op_ex;
+use crate::{matrix::{Matrix, MatrixParseError, NotInvertibleError}, tuple::{Tuple, Point, Vector}, util::{feq, Float}};
+
+// A 4x4 matrix that lives on the stack. Every transform in a scene is 4x4, so this is what
+// shapes, cameras and rays use; the general `Matrix` is still around for the smaller
+// matrices that the book builds up the determinant from.
+#[derive(Debug, Clone, Copy)]
+pub struct Matrix4 {
+    n: [[Float; 4]; 4],
+}
+
+impl Matrix4 {
+    pub fn new(rows: [[Float; 4]; 4]) -> Matrix4 {
+        Matrix4 { n: rows }
+    }
+    pub fn identity() -> Self {
+        Self::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+    pub fn translation(x: Float, y: Float, z: Float) -> Self {
+        let mut m = Self::identity();
+        m[(0,3)] = x;
+        m[(1,3)] = y;
+        m[(2,3)] = z;
+        m
+    }
+    pub fn scaling(x: Float, y: Float, z: Float) -> Self {
+        let mut m = Self::identity();
+        m[(0,0)] = x;
+        m[(1,1)] = y;
+        m[(2,2)] = z;
+        m
+    }
+    pub fn rotation_x(radians: Float) -> Self {
+        let mut m = Self::identity();
+        m[(1,1)] = radians.cos();
+        m[(2,1)] = radians.sin();
+        m[(1,2)] = -m[(2,1)];
+        m[(2,2)] = m[(1,1)];
+        m
+    }
+    pub fn rotation_y(radians: Float) -> Self {
+        let mut m = Self::identity();
+        m[(0,0)] = radians.cos();
+        m[(0,2)] = radians.sin();
+        m[(2,0)] = -m[(0,2)];
+        m[(2,2)] = m[(0,0)];
+        m
+    }
+    pub fn rotation_z(radians: Float) -> Self {
+        let mut m = Self::identity();
+        m[(0,0)] = radians.cos();
+        m[(1,0)] = radians.sin();
+        m[(0,1)] = -m[(1,0)];
+        m[(1,1)] = m[(0,0)];
+        m
+    }
+    pub fn shear(xy: Float, xz: Float, yx: Float, yz: Float, zx: Float, zy: Float) -> Self {
+        let mut m = Self::identity();
+        m[(0,1)] = xy;
+        m[(0,2)] = xz;
+        m[(1,0)] = yx;
+        m[(1,2)] = yz;
+        m[(2,0)] = zx;
+        m[(2,1)] = zy;
+        m
+    }
+
+    // Chainable versions of the constructors above. Each one applies its transform *after*
+    // everything already in the chain, so they read in the order they happen:
+    // `Matrix4::identity().rotate_x(a).scale(x, y, z).translate(x, y, z)`
+    // is the same as `translation(..) * scaling(..) * rotation_x(a)`
+    pub fn translate(self, x: Float, y: Float, z: Float) -> Self {
+        Self::translation(x, y, z) * self
+    }
+    pub fn scale(self, x: Float, y: Float, z: Float) -> Self {
+        Self::scaling(x, y, z) * self
+    }
+    pub fn rotate_x(self, radians: Float) -> Self {
+        Self::rotation_x(radians) * self
+    }
+    pub fn rotate_y(self, radians: Float) -> Self {
+        Self::rotation_y(radians) * self
+    }
+    pub fn rotate_z(self, radians: Float) -> Self {
+        Self::rotation_z(radians) * self
+    }
+    // `shear` is already taken by the constructor
+    pub fn skew(self, xy: Float, xz: Float, yx: Float, yz: Float, zx: Float, zy: Float) -> Self {
+        Self::shear(xy, xz, yx, yz, zx, zy) * self
+    }
+
+    // page 100
+    // In book: view_transform(from, to, up)
+    pub fn view_transform(from: &Point, to: &Point, up: &Vector) -> Result<Self,Box<dyn Error>> {
+        let forward = (to - from).normalize()?;
+        let left = forward.cross(&up.normalize()?)?;
+        let up = left.cross(&forward)?;
+        let orientation = Self::new([
+            [left.x, left.y, left.z, 0.0],
+            [up.x, up.y, up.z, 0.0],
+            [-forward.x, -forward.y, -forward.z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        Ok(orientation * Self::translation(-from.x, -from.y, -from.z))
+    }
+
+    pub fn transpose(&self) -> Matrix4 {
+        let mut m = Self::new([[0.0; 4]; 4]);
+        for r in 0..4 {
+            for c in 0..4 {
+                m[(r,c)] = self[(c,r)];
+            }
+        }
+        m
+    }
+
+    // The 2x2 determinants from the top two and bottom two rows. Both the determinant and the
+    // inverse are built out of these, which avoids the recursion through submatrices.
+    fn pair_determinants(&self) -> ([Float; 6], [Float; 6]) {
+        let m = &self.n;
+        let s = [
+            m[0][0] * m[1][1] - m[1][0] * m[0][1],
+            m[0][0] * m[1][2] - m[1][0] * m[0][2],
+            m[0][0] * m[1][3] - m[1][0] * m[0][3],
+            m[0][1] * m[1][2] - m[1][1] * m[0][2],
+            m[0][1] * m[1][3] - m[1][1] * m[0][3],
+            m[0][2] * m[1][3] - m[1][2] * m[0][3],
+        ];
+        let c = [
+            m[2][0] * m[3][1] - m[3][0] * m[2][1],
+            m[2][0] * m[3][2] - m[3][0] * m[2][2],
+            m[2][0] * m[3][3] - m[3][0] * m[2][3],
+            m[2][1] * m[3][2] - m[3][1] * m[2][2],
+            m[2][1] * m[3][3] - m[3][1] * m[2][3],
+            m[2][2] * m[3][3] - m[3][2] * m[2][3],
+        ];
+        (s, c)
+    }
+
+    pub fn determinant(&self) -> Float {
+        let (s, c) = self.pair_determinants();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    pub fn contains_nan(&self) -> bool {
+        self.n.iter().flatten().any(|f| f.is_nan())
+    }
+
+    pub fn is_invertible(&self) -> bool {
+        let det = self.determinant();
+        det != 0.0 && det.is_finite()
+    }
+
+    pub fn inverse(&self) -> Result<Matrix4, NotInvertibleError> {
+        let (s, c) = self.pair_determinants();
+        let det = s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+        if det == 0.0 || !det.is_finite() {
+            return Err(NotInvertibleError{})
+        }
+        let m = &self.n;
+        let inv = 1.0 / det;
+        let mut inverse = Self::new([
+            [
+                ( m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3]) * inv,
+                (-m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3]) * inv,
+                ( m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3]) * inv,
+                (-m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3]) * inv,
+            ],
+            [
+                (-m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1]) * inv,
+                ( m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1]) * inv,
+                (-m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1]) * inv,
+                ( m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1]) * inv,
+            ],
+            [
+                ( m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0]) * inv,
+                (-m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0]) * inv,
+                ( m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0]) * inv,
+                (-m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0]) * inv,
+            ],
+            [
+                (-m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0]) * inv,
+                ( m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0]) * inv,
+                (-m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0]) * inv,
+                ( m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0]) * inv,
+            ],
+        ]);
+        // Rounding can leave the bottom corner a hair away from 1, which turns points into
+        // not-quite-points. Transforms are affine, and so are their inverses, so keep it exact.
+        if m[3] == [0.0, 0.0, 0.0, 1.0] {
+            inverse.n[3] = [0.0, 0.0, 0.0, 1.0];
+        }
+        Ok(inverse)
+    }
+}
+
+impl FromStr for Matrix4 {
+    type Err = MatrixParseError;
+
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        Ok(Matrix4::from(&Matrix::from_str(4, 4, contents)?))
+    }
+}
+
+impl From<&Matrix> for Matrix4 {
+    fn from(value: &Matrix) -> Self {
+        if value.height() != 4 || value.width() != 4 {
+            panic!("Expected a 4x4 matrix but found {} x {}", value.height(), value.width());
+        }
+        let mut m = Self::new([[0.0; 4]; 4]);
+        for r in 0..4 {
+            for c in 0..4 {
+                m[(r,c)] = value[(r,c)];
+            }
+        }
+        m
+    }
+}
+
+impl From<&Matrix4> for Matrix {
+    fn from(value: &Matrix4) -> Self {
+        let mut m = Matrix::new(4, 4);
+        for r in 0..4 {
+            for c in 0..4 {
+                m[(r,c)] = value[(r,c)];
+            }
+        }
+        m
+    }
+}
+
+impl Index<(usize,usize)> for Matrix4 {
+    type Output=Float;
+
+    fn index(&self, index: (usize,usize)) -> &Self::Output {
+        &self.n[index.0][index.1]
+    }
+}
+impl IndexMut<(usize,usize)> for Matrix4 {
+    fn index_mut(&mut self, index: (usize,usize)) -> &mut Float {
+        &mut self.n[index.0][index.1]
+    }
+}
+
+impl PartialEq for Matrix4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.n.iter().flatten()
+            .zip(other.n.iter().flatten())
+            .all(|(a,b)| { feq(a, b) })
+    }
+}
+
+impl_op_ex!(* |a: &Matrix4, b: &Matrix4| -> Matrix4 {
+    let mut product = Matrix4::new([[0.0; 4]; 4]);
+    for r in 0..4 {
+        for c in 0..4 {
+            product[(r,c)] = a[(r,0)] * b[(0,c)]
+                + a[(r,1)] * b[(1,c)]
+                + a[(r,2)] * b[(2,c)]
+                + a[(r,3)] * b[(3,c)];
+        }
+    }
+    product
+});
+
+impl_op_ex!(* |a: &Matrix4, b: &Tuple| -> Tuple {
+    let sum_row = |r:usize| -> Float {
+        a[(r,0)] * b.x + a[(r,1)] * b.y + a[(r,2)] * b.z + a[(r,3)] * b.w
+    };
+    Tuple {x: sum_row(0), y: sum_row(1), z: sum_row(2), w: sum_row(3) }
+});
+
+impl_op_ex!(* |a: &Tuple, b: &Matrix4| -> Tuple {
+    b * a
+});
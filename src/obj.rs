@@ -0,0 +1,174 @@
+use std::{convert::Infallible, error::Error, fs, path::Path, str::FromStr};
+
+use log::warn;
+
+use crate::{
+    group::Group, intersectable::Intersectable, smooth_triangle::SmoothTriangle, triangle::Triangle,
+    tuple::{Point, Tuple, Vector}, util::Float,
+};
+
+// pg 212
+// In book: parse_obj_file(file)
+// A Wavefront OBJ mesh. Only the parts that describe shape are read: vertices (`v`), vertex
+// normals (`vn`), faces (`f`) and groups (`g`). Everything else, and any line that can't be
+// made sense of, is skipped and counted in `ignored`; malformed lines also log a warning.
+// Faces with more than three corners are split into a fan of triangles, and faces that give a
+// normal for every corner become smooth triangles.
+#[derive(Debug, Default)]
+pub struct ObjFile {
+    pub vertices: Vec<Point>,
+    pub normals: Vec<Vector>,
+    // faces that come before any `g` line
+    pub default_group: Vec<Box<dyn Intersectable>>,
+    // in the order they first appear; a name that's used again adds to the earlier group
+    pub groups: Vec<(String, Vec<Box<dyn Intersectable>>)>,
+    pub ignored: usize,
+}
+
+impl ObjFile {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<ObjFile, Box<dyn Error>> {
+        let contents = fs::read_to_string(path)?;
+        Ok(contents.parse()?)
+    }
+
+    pub fn group(&self, name: &str) -> Option<&Vec<Box<dyn Intersectable>>> {
+        self.groups.iter().find(|(n, _)| n == name).map(|(_, shapes)| shapes)
+    }
+
+    // Every triangle in the file, whichever group it was in
+    pub fn into_shapes(self) -> Vec<Box<dyn Intersectable>> {
+        let mut shapes = self.default_group;
+        for (_, group) in self.groups {
+            shapes.extend(group);
+        }
+        shapes
+    }
+
+    // pg 218
+    // In book: obj_to_group(parser)
+    // The triangles outside any named group go straight into the group; each named group
+    // becomes a group of its own inside it
+    pub fn into_group(self) -> Group {
+        let mut group = Group::new();
+        for shape in self.default_group {
+            group.add_child(shape);
+        }
+        for (_, shapes) in self.groups {
+            let mut named = Group::new();
+            for shape in shapes {
+                named.add_child(shape);
+            }
+            group.add_child(Box::new(named));
+        }
+        group
+    }
+
+    fn current_group(&mut self, name: &Option<String>) -> &mut Vec<Box<dyn Intersectable>> {
+        match name {
+            None => &mut self.default_group,
+            Some(name) => {
+                let index = match self.groups.iter().position(|(n, _)| n == name) {
+                    Some(index) => index,
+                    None => {
+                        self.groups.push((name.clone(), vec![]));
+                        self.groups.len() - 1
+                    },
+                };
+                &mut self.groups[index].1
+            },
+        }
+    }
+
+    // OBJ counts from 1, and negative numbers count back from the most recent entry
+    fn lookup<T: Copy>(list: &[T], index: &str) -> Result<T, String> {
+        let i: i64 = index.parse().map_err(|_| format!("`{}` isn't an index", index))?;
+        let position = match i {
+            i if i > 0 => i as usize - 1,
+            i if i < 0 && (i.unsigned_abs() as usize) <= list.len() => list.len() - i.unsigned_abs() as usize,
+            _ => return Err(format!("there's no entry {}", i)),
+        };
+        list.get(position).copied().ok_or_else(|| format!("there's no entry {} (only {} so far)", i, list.len()))
+    }
+
+    fn parse_xyz(args: &[&str]) -> Result<(Float, Float, Float), String> {
+        // `v` may have a fourth (w) coordinate, which doesn't matter for triangles
+        if args.len() < 3 || args.len() > 4 {
+            return Err(format!("expected 3 numbers but found {}", args.len()));
+        }
+        let mut numbers = [0.0; 3];
+        for (n, arg) in numbers.iter_mut().zip(args) {
+            *n = arg.parse().map_err(|_| format!("`{}` isn't a number", arg))?;
+        }
+        Ok((numbers[0], numbers[1], numbers[2]))
+    }
+
+    // pg 216 for the fan
+    fn parse_face(&self, args: &[&str]) -> Result<Vec<Box<dyn Intersectable>>, String> {
+        if args.len() < 3 {
+            return Err(format!("a face needs at least 3 corners but found {}", args.len()));
+        }
+        // each corner is `v`, `v/vt`, `v//vn` or `v/vt/vn`; texture coordinates aren't used
+        let mut points = vec![];
+        let mut normals = vec![];
+        for corner in args {
+            let mut parts = corner.split('/');
+            points.push(Self::lookup(&self.vertices, parts.next().unwrap_or(""))?);
+            if let Some(normal) = parts.nth(1).filter(|n| !n.is_empty()) {
+                normals.push(Self::lookup(&self.normals, normal)?);
+            }
+        }
+        let smooth = normals.len() == points.len();
+        let triangles = (1..points.len() - 1)
+            .filter(|&i| {
+                let degenerate = !Triangle::has_area(&points[0], &points[i], &points[i + 1]);
+                if degenerate {
+                    warn!("skipping a triangle with no area in face `f {}`", args.join(" "));
+                }
+                !degenerate
+            })
+            .map(|i| -> Box<dyn Intersectable> {
+                if smooth {
+                    Box::new(SmoothTriangle::new(points[0], points[i], points[i + 1], normals[0], normals[i], normals[i + 1]))
+                } else {
+                    Box::new(Triangle::new(points[0], points[i], points[i + 1]))
+                }
+            })
+            .collect();
+        Ok(triangles)
+    }
+}
+
+impl FromStr for ObjFile {
+    // Bad lines are skipped rather than failing the whole file
+    type Err = Infallible;
+
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        let mut obj = ObjFile::default();
+        let mut group: Option<String> = None;
+        for (i, line) in contents.lines().enumerate() {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let Some((&command, args)) = words.split_first() else {
+                continue;
+            };
+            let result = match command {
+                "v" => Self::parse_xyz(args).map(|(x, y, z)| obj.vertices.push(Tuple::new_point(x, y, z))),
+                "vn" => Self::parse_xyz(args).map(|(x, y, z)| obj.normals.push(Tuple::new_vector(x, y, z))),
+                "f" => obj.parse_face(args).map(|triangles| obj.current_group(&group).extend(triangles)),
+                "g" if args.is_empty() => Err("a group needs a name".to_string()),
+                "g" => {
+                    group = Some(args.join(" "));
+                    Ok(())
+                },
+                _ => {
+                    obj.ignored += 1;
+                    continue;
+                },
+            };
+            if let Err(message) = result {
+                warn!("obj line {}: skipping `{}`: {}", i + 1, line.trim(), message);
+                obj.ignored += 1;
+            }
+        }
+        Ok(obj)
+    }
+}
@@ -0,0 +1,288 @@
+use std::{fmt::Debug, sync::Arc};
+
+use crate::{color::Color, intersectable::Intersectable, matrix::NotInvertibleError, matrix4::Matrix4, perlin, tuple::{Point, Tuple}, util::Float};
+
+// Where a pattern sits relative to the object it's painted on. Like `Shape`, the inverse is
+// worked out whenever the transform changes since every lookup needs it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PatternSpace {
+    transform: Matrix4,
+    inverse: Matrix4,
+}
+
+impl Default for PatternSpace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PatternSpace {
+    pub fn new() -> PatternSpace {
+        PatternSpace { transform: Matrix4::identity(), inverse: Matrix4::identity() }
+    }
+
+    pub fn transformation(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    pub fn inverse(&self) -> &Matrix4 {
+        &self.inverse
+    }
+
+    pub fn set_transform(&mut self, trans: Matrix4) -> Result<(), NotInvertibleError> {
+        self.inverse = trans.inverse()?;
+        self.transform = trans;
+        Ok(())
+    }
+}
+
+// Every pattern works out its color in its own pattern space (`local_color_at`); the default
+// methods take a point from world space to object space to pattern space first.
+// Materials (and so patterns) are shared between render threads, hence `Send + Sync`.
+pub trait Pattern: Debug + Send + Sync {
+    fn space(&self) -> &PatternSpace;
+    fn mut_space(&mut self) -> &mut PatternSpace;
+    // In book: pattern_at(pattern, point)
+    fn local_color_at(&self, point: &Point) -> Color;
+
+    fn transformation(&self) -> &Matrix4 {
+        self.space().transformation()
+    }
+
+    fn set_transform(&mut self, trans: Matrix4) -> Result<(), NotInvertibleError> {
+        self.mut_space().set_transform(trans)
+    }
+
+    // pg 131
+    // In book: pattern_at_shape(pattern, object, world_point)
+    fn color_at_object(&self, object: &dyn Intersectable, world_point: &Point) -> Color {
+        let object_point = object.world_to_object(world_point);
+        let pattern_point = self.space().inverse() * object_point;
+        self.local_color_at(&pattern_point)
+    }
+}
+
+// What a pattern fills its parts with: either a plain color or another pattern, so patterns
+// can be nested (checkers of stripes, say). A nested pattern is looked up in the space of the
+// pattern containing it, and then its own transform is applied on top of that.
+#[derive(Debug, Clone)]
+pub enum Paint {
+    Solid(Color),
+    Pattern(Arc<dyn Pattern>),
+}
+
+impl Paint {
+    pub fn color_at(&self, point: &Point) -> Color {
+        match self {
+            Paint::Solid(color) => *color,
+            Paint::Pattern(pattern) => pattern.local_color_at(&(pattern.space().inverse() * point)),
+        }
+    }
+}
+
+impl From<Color> for Paint {
+    fn from(color: Color) -> Self {
+        Paint::Solid(color)
+    }
+}
+
+impl<P: Pattern + 'static> From<P> for Paint {
+    fn from(pattern: P) -> Self {
+        Paint::Pattern(Arc::new(pattern))
+    }
+}
+
+impl PartialEq<Color> for Paint {
+    fn eq(&self, other: &Color) -> bool {
+        match self {
+            Paint::Solid(color) => color == other,
+            Paint::Pattern(_) => false,
+        }
+    }
+}
+
+// pg 128
+// In book: stripe_pattern(a, b)
+// Alternates between a and b every unit along x
+#[derive(Debug, Clone)]
+pub struct StripePattern {
+    pub a: Paint,
+    pub b: Paint,
+    space: PatternSpace,
+}
+
+impl StripePattern {
+    pub fn new(a: impl Into<Paint>, b: impl Into<Paint>) -> StripePattern {
+        StripePattern { a: a.into(), b: b.into(), space: PatternSpace::new() }
+    }
+}
+
+impl Pattern for StripePattern {
+    fn space(&self) -> &PatternSpace {
+        &self.space
+    }
+    fn mut_space(&mut self) -> &mut PatternSpace {
+        &mut self.space
+    }
+    fn local_color_at(&self, point: &Point) -> Color {
+        if point.x.floor() as i64 % 2 == 0 {
+            self.a.color_at(point)
+        } else {
+            self.b.color_at(point)
+        }
+    }
+}
+
+// pg 135
+// In book: gradient_pattern(a, b)
+// Blends from a to b as x goes from 0 to 1, then starts over
+#[derive(Debug, Clone)]
+pub struct GradientPattern {
+    pub a: Paint,
+    pub b: Paint,
+    space: PatternSpace,
+}
+
+impl GradientPattern {
+    pub fn new(a: impl Into<Paint>, b: impl Into<Paint>) -> GradientPattern {
+        GradientPattern { a: a.into(), b: b.into(), space: PatternSpace::new() }
+    }
+}
+
+impl Pattern for GradientPattern {
+    fn space(&self) -> &PatternSpace {
+        &self.space
+    }
+    fn mut_space(&mut self) -> &mut PatternSpace {
+        &mut self.space
+    }
+    fn local_color_at(&self, point: &Point) -> Color {
+        let fraction = point.x - point.x.floor();
+        let a = self.a.color_at(point);
+        a + (self.b.color_at(point) - a) * fraction
+    }
+}
+
+// pg 136
+// In book: ring_pattern(a, b)
+// Concentric rings around the y axis, alternating every unit of distance
+#[derive(Debug, Clone)]
+pub struct RingPattern {
+    pub a: Paint,
+    pub b: Paint,
+    space: PatternSpace,
+}
+
+impl RingPattern {
+    pub fn new(a: impl Into<Paint>, b: impl Into<Paint>) -> RingPattern {
+        RingPattern { a: a.into(), b: b.into(), space: PatternSpace::new() }
+    }
+}
+
+impl Pattern for RingPattern {
+    fn space(&self) -> &PatternSpace {
+        &self.space
+    }
+    fn mut_space(&mut self) -> &mut PatternSpace {
+        &mut self.space
+    }
+    fn local_color_at(&self, point: &Point) -> Color {
+        let distance: Float = (point.x.powi(2) + point.z.powi(2)).sqrt();
+        if distance.floor() as i64 % 2 == 0 {
+            self.a.color_at(point)
+        } else {
+            self.b.color_at(point)
+        }
+    }
+}
+
+// pg 137
+// In book: checkers_pattern(a, b)
+// Unit cubes alternating between a and b, in all three dimensions
+#[derive(Debug, Clone)]
+pub struct CheckersPattern {
+    pub a: Paint,
+    pub b: Paint,
+    space: PatternSpace,
+}
+
+impl CheckersPattern {
+    pub fn new(a: impl Into<Paint>, b: impl Into<Paint>) -> CheckersPattern {
+        CheckersPattern { a: a.into(), b: b.into(), space: PatternSpace::new() }
+    }
+}
+
+impl Pattern for CheckersPattern {
+    fn space(&self) -> &PatternSpace {
+        &self.space
+    }
+    fn mut_space(&mut self) -> &mut PatternSpace {
+        &mut self.space
+    }
+    fn local_color_at(&self, point: &Point) -> Color {
+        let sum = point.x.floor() + point.y.floor() + point.z.floor();
+        if sum as i64 % 2 == 0 {
+            self.a.color_at(point)
+        } else {
+            self.b.color_at(point)
+        }
+    }
+}
+
+// pg 138
+// Averages two patterns at every point
+#[derive(Debug, Clone)]
+pub struct BlendedPattern {
+    pub a: Paint,
+    pub b: Paint,
+    space: PatternSpace,
+}
+
+impl BlendedPattern {
+    pub fn new(a: impl Into<Paint>, b: impl Into<Paint>) -> BlendedPattern {
+        BlendedPattern { a: a.into(), b: b.into(), space: PatternSpace::new() }
+    }
+}
+
+impl Pattern for BlendedPattern {
+    fn space(&self) -> &PatternSpace {
+        &self.space
+    }
+    fn mut_space(&mut self) -> &mut PatternSpace {
+        &mut self.space
+    }
+    fn local_color_at(&self, point: &Point) -> Color {
+        (self.a.color_at(point) + self.b.color_at(point)) * 0.5
+    }
+}
+
+// pg 139
+// Jitters the point with Perlin noise before looking it up in the inner pattern, so straight
+// stripes come out wavy. `scale` is how far (at most, roughly) a point can be moved.
+#[derive(Debug, Clone)]
+pub struct PerturbedPattern {
+    pub pattern: Paint,
+    pub scale: Float,
+    space: PatternSpace,
+}
+
+impl PerturbedPattern {
+    pub fn new(pattern: impl Into<Paint>, scale: Float) -> PerturbedPattern {
+        PerturbedPattern { pattern: pattern.into(), scale, space: PatternSpace::new() }
+    }
+}
+
+impl Pattern for PerturbedPattern {
+    fn space(&self) -> &PatternSpace {
+        &self.space
+    }
+    fn mut_space(&mut self) -> &mut PatternSpace {
+        &mut self.space
+    }
+    fn local_color_at(&self, point: &Point) -> Color {
+        // offset the lookups so each axis gets its own noise
+        let offset = |dz: Float| perlin::noise(&(point + Tuple::new_vector(0.0, 0.0, dz))) * self.scale;
+        let jittered = point + Tuple::new_vector(offset(0.0), offset(1.0), offset(2.0));
+        self.pattern.color_at(&jittered)
+    }
+}
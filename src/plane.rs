@@ -0,0 +1,63 @@
+use crate::{
+    bounds::BoundingBox,
+    ray::Ray,
+    shape::Shape,
+    tuple::{Point, Tuple, Vector},
+    util::{Float, EPSILON}, intersectable::Intersectable, intersection::Intersection,
+};
+
+// An infinite plane. In object space it is the xz-plane (y = 0)
+#[derive(Debug, PartialEq, Clone)]
+pub struct Plane {
+    shape: Shape,
+}
+
+impl Default for Plane {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Plane {
+    // In book: plane()
+    pub fn new() -> Plane {
+        Plane { shape: Shape::new() }
+    }
+}
+
+impl Intersectable for Plane {
+    fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn mut_shape(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    // pg 123
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        // parallel or coplanar rays never (or always) touch the plane; either way there's nothing to see
+        if ray.direction.y.abs() < EPSILON {
+            return vec![];
+        }
+        let t = -ray.origin.y / ray.direction.y;
+        vec![Intersection::new(t, self)]
+    }
+
+    // pg 122: the normal is the same everywhere on the plane
+    fn local_normal_at(&self, _point: &Point) -> Vector {
+        Tuple::new_vector(0.0, 1.0, 0.0)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    // flat, and endless in x and z
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Tuple::new_point(Float::NEG_INFINITY, 0.0, Float::NEG_INFINITY),
+            Tuple::new_point(Float::INFINITY, 0.0, Float::INFINITY),
+        )
+    }
+}
@@ -0,0 +1,128 @@
+use crate::util::Float;
+
+// Real roots of polynomials, for shapes (like `Torus`) whose surfaces are too curved for the
+// quadratic formula. Coefficients go from the highest power down, so `[1.0, -3.0, 2.0]` is
+// x² - 3x + 2.
+//
+// Rather than the closed-form cubic and quartic formulas, which lose most of their precision
+// when roots are close together, the roots of the derivative split the line into pieces where
+// the polynomial only goes up or only goes down. Each piece can then hold at most one root,
+// found by Newton's method kept inside the piece by bisection.
+//
+// A root where the polynomial only touches zero (a tangent, like a ray grazing a surface) is
+// listed twice: the ray goes into the surface and straight back out, so the hits still come in
+// pairs for CSG and refraction, which count them to know when the ray is inside a shape. A root
+// where it flattens out but crosses (like x³) is listed once. Touching is judged with a little
+// slack for rounding, so a ray that misses by far less than the rounding error counts.
+pub fn real_roots(coefficients: &[Float]) -> Vec<Float> {
+    let first = coefficients.iter().position(|c| *c != 0.0).unwrap_or(coefficients.len());
+    let coefficients = &coefficients[first..];
+    let mut roots = match coefficients.len() {
+        0 | 1 => vec![],
+        2 => vec![-coefficients[1] / coefficients[0]],
+        3 => solve_quadratic(coefficients[0], coefficients[1], coefficients[2]),
+        _ => isolate_roots(coefficients),
+    };
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    roots
+}
+
+// a x² + b x + c = 0, in the stable form: the root furthest from zero first, then the other
+// from the product of the roots, so nothing is lost subtracting two nearly equal numbers
+pub fn solve_quadratic(a: Float, b: Float, c: Float) -> Vec<Float> {
+    if a == 0.0 {
+        return real_roots(&[b, c]);
+    }
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return vec![];
+    }
+    let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+    if discriminant == 0.0 {
+        let root = -b / (2.0 * a);
+        return vec![root, root];
+    }
+    vec![q / a, c / q]
+}
+
+// a x⁴ + b x³ + c x² + d x + e = 0
+pub fn solve_quartic(a: Float, b: Float, c: Float, d: Float, e: Float) -> Vec<Float> {
+    real_roots(&[a, b, c, d, e])
+}
+
+pub fn evaluate(coefficients: &[Float], x: Float) -> Float {
+    coefficients.iter().fold(0.0, |total, c| total * x + c)
+}
+
+pub fn derivative(coefficients: &[Float]) -> Vec<Float> {
+    let degree = coefficients.len().saturating_sub(1);
+    coefficients[..degree].iter().enumerate()
+        .map(|(i, c)| c * (degree - i) as Float)
+        .collect()
+}
+
+// How close to zero counts as zero at `x`: a little more than the rounding error in adding
+// up the terms
+fn tolerance(coefficients: &[Float], x: Float) -> Float {
+    let magnitude = coefficients.iter().fold(0.0, |total, c| total * x.abs() + c.abs());
+    magnitude * 1e-12
+}
+
+fn isolate_roots(coefficients: &[Float]) -> Vec<Float> {
+    // Cauchy's bound: every root is within this distance of zero
+    let leading = coefficients[0];
+    let bound = 1.0 + coefficients[1..].iter().map(|c| (c / leading).abs()).fold(0.0, Float::max);
+
+    let mut points = vec![-bound];
+    let mut critical = real_roots(&derivative(coefficients));
+    critical.dedup();
+    points.extend(critical.into_iter().filter(|x| -bound < *x && *x < bound));
+    points.push(bound);
+
+    let values: Vec<Float> = points.iter()
+        .map(|x| {
+            let value = evaluate(coefficients, *x);
+            if value.abs() <= tolerance(coefficients, *x) { 0.0 } else { value }
+        })
+        .collect();
+
+    let mut roots = vec![];
+    for i in 0..points.len() {
+        if values[i] == 0.0 {
+            roots.push(points[i]);
+            // the same sign on both sides means it only touched zero
+            if 0 < i && i + 1 < points.len() && values[i - 1] * values[i + 1] > 0.0 {
+                roots.push(points[i]);
+            }
+        }
+        if i + 1 < points.len() && values[i] * values[i + 1] < 0.0 {
+            roots.push(bracketed_root(coefficients, points[i], points[i + 1], values[i]));
+        }
+    }
+    roots
+}
+
+// Newton's method, falling back on bisection whenever a step would leave the bracket. The
+// polynomial changes sign between `lo` and `hi` (`lo_value` is its value at `lo`).
+fn bracketed_root(coefficients: &[Float], mut lo: Float, mut hi: Float, lo_value: Float) -> Float {
+    let slope = derivative(coefficients);
+    let mut x = (lo + hi) / 2.0;
+    for _ in 0..100 {
+        let value = evaluate(coefficients, x);
+        if value == 0.0 {
+            return x;
+        }
+        if (value < 0.0) == (lo_value < 0.0) {
+            lo = x;
+        } else {
+            hi = x;
+        }
+        let step = x - value / evaluate(&slope, x);
+        let next = if lo < step && step < hi { step } else { (lo + hi) / 2.0 };
+        if (next - x).abs() <= Float::EPSILON * x.abs().max(1.0) {
+            return next;
+        }
+        x = next;
+    }
+    x
+}
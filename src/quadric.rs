@@ -0,0 +1,192 @@
+use crate::{
+    bounds::BoundingBox,
+    ray::Ray,
+    shape::Shape,
+    polynomial,
+    tuple::{Point, Tuple, Vector},
+    util::{Float, EPSILON}, intersectable::Intersectable, intersection::Intersection,
+};
+
+// Any surface where
+//
+//   a x² + b y² + c z² + d xy + e xz + f yz + g x + h y + i z + j = 0
+//
+// with `coefficients` in that order. Spheres, cylinders and cones are all quadrics; so are
+// ellipsoids, paraboloids and hyperboloids. Points where the left side is negative are inside.
+// Like `Cylinder`, it can be cut off at `minimum`/`maximum` along y (those ends aren't
+// included), and a `closed` quadric has caps on the ends.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Quadric {
+    pub coefficients: [Float; 10],
+    pub minimum: Float,
+    pub maximum: Float,
+    pub closed: bool,
+    shape: Shape,
+}
+
+impl Quadric {
+    pub fn new(coefficients: [Float; 10]) -> Quadric {
+        Quadric { coefficients, minimum: Float::NEG_INFINITY, maximum: Float::INFINITY, closed: false, shape: Shape::new() }
+    }
+
+    // x²/rx² + y²/ry² + z²/rz² = 1: a sphere stretched along the axes
+    pub fn ellipsoid(rx: Float, ry: Float, rz: Float) -> Quadric {
+        Self::new([1.0 / (rx * rx), 1.0 / (ry * ry), 1.0 / (rz * rz), 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0])
+    }
+
+    // y = x² + z²: a bowl opening upwards from the origin
+    pub fn paraboloid() -> Quadric {
+        Self::new([1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0])
+    }
+
+    // x² + z² = y² + 1: a cooling tower, narrowest (radius 1) at y = 0
+    pub fn hyperboloid_one_sheet() -> Quadric {
+        Self::new([1.0, -1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0])
+    }
+
+    // x² + z² = y² - 1: two bowls, one opening up from y = 1 and one down from y = -1
+    pub fn hyperboloid_two_sheets() -> Quadric {
+        Self::new([1.0, -1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0])
+    }
+
+    // x²/rx² + z²/rz² = 1: a cylinder around the y axis with an oval cross-section
+    pub fn elliptic_cylinder(rx: Float, rz: Float) -> Quadric {
+        Self::new([1.0 / (rx * rx), 0.0, 1.0 / (rz * rz), 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -1.0])
+    }
+
+    // Cuts the quadric off along y, like `Cylinder::new_truncated`
+    pub fn truncated(self, minimum: Float, maximum: Float, closed: bool) -> Quadric {
+        Quadric { minimum, maximum, closed, ..self }
+    }
+
+    // The left side of the equation at `point`
+    pub fn value_at(&self, point: &Point) -> Float {
+        let [a, b, c, d, e, f, g, h, i, j] = self.coefficients;
+        let (x, y, z) = (point.x, point.y, point.z);
+        a * x * x + b * y * y + c * z * z + d * x * y + e * x * z + f * y * z + g * x + h * y + i * z + j
+    }
+
+    // Same as the cylinder's caps, except that the cap is whatever part of the plane is inside
+    fn intersect_caps<'a>(&'a self, ray: &Ray, xs: &mut Vec<Intersection<'a>>) {
+        if !self.closed || ray.direction.y.abs() < EPSILON {
+            return;
+        }
+        for cap in [self.minimum, self.maximum] {
+            if !cap.is_finite() {
+                continue;
+            }
+            let t = (cap - ray.origin.y) / ray.direction.y;
+            if self.value_at(&ray.position(&t)) <= 0.0 {
+                xs.push(Intersection::new(t, self));
+            }
+        }
+    }
+
+    // How far out the quadric reaches between `minimum` and `maximum`. Along x and z this is only
+    // worked out when every slice across y is an ellipse centered on the y axis (which is the
+    // case for all of the named quadrics); anything else is treated as going on forever.
+    fn slice_bounds(&self) -> Option<BoundingBox> {
+        let [a, b, c, d, e, f, g, h, i, j] = self.coefficients;
+        if a <= 0.0 || c <= 0.0 || d != 0.0 || e != 0.0 || f != 0.0 || g != 0.0 || i != 0.0 {
+            return None;
+        }
+        // at height y the slice is a x² + c z² = s(y)
+        let s = |y: Float| -(b * y * y + h * y + j);
+        let (mut low, mut high) = (self.minimum, self.maximum);
+        if b > 0.0 {
+            // s(y) is only positive between its roots, so the quadric is closed off at both ends
+            let roots = polynomial::real_roots(&[-b, -h, -j]);
+            if roots.len() < 2 {
+                return Some(BoundingBox::empty());
+            }
+            low = low.max(roots[0]);
+            high = high.min(roots[1]);
+        }
+        if low > high {
+            return Some(BoundingBox::empty());
+        }
+        let slice_at = |y: Float| {
+            if y.is_finite() {
+                s(y)
+            } else if b < 0.0 || (b == 0.0 && h * y < 0.0) {
+                Float::INFINITY
+            } else if b == 0.0 && h == 0.0 {
+                -j
+            } else {
+                // it only narrows this way
+                0.0
+            }
+        };
+        let mut heights = vec![low, high];
+        if b != 0.0 {
+            // the top (or bottom) of the parabola s(y)
+            heights.push((-h / (2.0 * b)).clamp(low, high));
+        }
+        let widest = heights.into_iter().map(slice_at).fold(0.0, Float::max);
+        let (rx, rz) = ((widest / a).sqrt(), (widest / c).sqrt());
+        Some(BoundingBox::new(Tuple::new_point(-rx, low, -rz), Tuple::new_point(rx, high, rz)))
+    }
+}
+
+impl Intersectable for Quadric {
+    fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn mut_shape(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    // Putting the ray into the equation gives a quadratic in t. When the quadratic term drops
+    // out (a ray parallel to a paraboloid's axis) there's only one hit.
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let [a, b, c, d, e, f, g, h, i, _] = self.coefficients;
+        let (o, v) = (ray.origin, ray.direction);
+        let qa = a * v.x * v.x + b * v.y * v.y + c * v.z * v.z + d * v.x * v.y + e * v.x * v.z + f * v.y * v.z;
+        let qb = 2.0 * (a * o.x * v.x + b * o.y * v.y + c * o.z * v.z)
+            + d * (o.x * v.y + o.y * v.x) + e * (o.x * v.z + o.z * v.x) + f * (o.y * v.z + o.z * v.y)
+            + g * v.x + h * v.y + i * v.z;
+        let qc = self.value_at(&o);
+
+        let mut xs: Vec<Intersection> = polynomial::real_roots(&[qa, qb, qc])
+            .into_iter()
+            .filter(|t| {
+                let y = o.y + t * v.y;
+                self.minimum < y && y < self.maximum
+            })
+            .map(|t| Intersection::new(t, self))
+            .collect();
+        self.intersect_caps(ray, &mut xs);
+        xs
+    }
+
+    // The gradient of the equation, which points away from the inside. Points on the caps face
+    // straight up or down.
+    fn local_normal_at(&self, point: &Point) -> Vector {
+        let inside = self.value_at(point) < 0.0;
+        if self.closed && inside && point.y >= self.maximum - EPSILON {
+            return Tuple::new_vector(0.0, 1.0, 0.0);
+        }
+        if self.closed && inside && point.y <= self.minimum + EPSILON {
+            return Tuple::new_vector(0.0, -1.0, 0.0);
+        }
+        let [a, b, c, d, e, f, g, h, i, _] = self.coefficients;
+        let (x, y, z) = (point.x, point.y, point.z);
+        Tuple::new_vector(
+            2.0 * a * x + d * y + e * z + g,
+            2.0 * b * y + d * x + f * z + h,
+            2.0 * c * z + e * x + f * y + i,
+        )
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.slice_bounds().unwrap_or_else(|| BoundingBox::new(
+            Tuple::new_point(Float::NEG_INFINITY, self.minimum, Float::NEG_INFINITY),
+            Tuple::new_point(Float::INFINITY, self.maximum, Float::INFINITY),
+        ))
+    }
+}
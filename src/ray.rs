@@ -1,7 +1,7 @@
 use auto_ops::*;
-use crate::{tuple::Tuple, util::Float, matrix::Matrix};
+use crate::{tuple::Tuple, util::Float, matrix4::Matrix4};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Ray {
     pub origin: Tuple,
     pub direction: Tuple,
@@ -18,9 +18,9 @@ impl Ray {
     }
 }
 
-impl_op_ex!(* |transform: &Matrix, ray: &Ray| -> Ray {
+impl_op_ex!(* |transform: &Matrix4, ray: &Ray| -> Ray {
     Ray{
-        origin: transform * &ray.origin,
-        direction: transform * &ray.direction,
+        origin: transform * ray.origin,
+        direction: transform * ray.direction,
     }
 });
\ No newline at end of file
@@ -0,0 +1,448 @@
+use std::{error::Error, fmt::Display, fs, path::{Path, PathBuf}, str::FromStr, f64::consts::PI};
+
+use crate::{
+    camera::Camera, color::Color, intersectable::Intersectable, light::Light, material::Material,
+    matrix4::Matrix4, plane::Plane, cube::Cube, cylinder::Cylinder, cone::Cone, torus::Torus, quadric::Quadric,
+    triangle::Triangle, obj::ObjFile, group::DEFAULT_DIVIDE_THRESHOLD, csg::{Csg, CsgOperation}, sphere::Sphere,
+    tuple::Tuple, util::Float, world::World,
+};
+
+// A scene file describes a camera, lights, materials and shapes, one per line:
+//
+//   # comments run to the end of the line
+//   camera width=400 height=200 fov=1.0472 from=0,1.5,-5 to=0,1,0 up=0,1,0
+//   light position=-10,10,-10 intensity=1,1,1
+//   material name=green color=0.1,1,0.5 diffuse=0.7 specular=0.3
+//   plane color=1,0.9,0.9 specular=0
+//   sphere material=green translate=-0.5,1,0.5
+//   sphere material=green color=0.5,1,0.1 scale=0.5,0.5,0.5 translate=1.5,0.5,-0.5
+//
+// Everything after the first word is `key=value`. Shapes start from a named material (if any)
+// and then any material keys on the line override it. The transform keys (translate, scale,
+// rotate_x, rotate_y, rotate_z, shear) are applied in the order they're written.
+//
+// The shapes are sphere, plane, cube, cylinder, cone, torus, quadric and triangle. Cylinders,
+// cones and quadrics can also be cut off with `minimum`/`maximum` and given end caps with
+// `closed=true`; a sphere can have a `center` (the origin unless given) and `radius` (1); a torus
+// takes `major_radius` (1) and `minor_radius` (0.25); a quadric is either `kind=paraboloid` (or
+// `hyperboloid` or `two_sheet_hyperboloid`) or any quadric surface from its ten `coefficients`
+// (see `Quadric`); triangles need their corners as `p1`, `p2` and `p3`. `obj file=teapot.obj`
+// brings in all the triangles from a Wavefront OBJ file (found relative to the scene file) as a
+// single group; big meshes are sorted into nested bounding boxes of at most `threshold` triangles
+// (8 unless given).
+//
+// `csg operation=difference` (or `union` or `intersection`) takes the two shapes just before
+// it and puts them together, the first on the left; it can only have transform keys, since the
+// two shapes keep their own materials. A CSG shape counts as one shape for the next `csg`, so
+// a die is a cube, a sphere, `csg operation=intersection`, a sphere for a pip, and then
+// `csg operation=difference`.
+#[derive(Debug)]
+pub struct Scene {
+    pub camera: Camera,
+    pub world: World,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SceneError {
+    pub line: Option<usize>,
+    pub message: String,
+}
+impl Error for SceneError {}
+impl Display for SceneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Scene {
+    // Files the scene refers to (OBJ meshes) are found relative to the scene file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Scene, Box<dyn Error>> {
+        let contents = fs::read_to_string(&path)?;
+        let base_dir = path.as_ref().parent().map(Path::to_path_buf);
+        Ok(Self::parse(&contents, base_dir)?)
+    }
+
+    fn parse(contents: &str, base_dir: Option<PathBuf>) -> Result<Scene, SceneError> {
+        let mut builder = SceneBuilder { base_dir, ..SceneBuilder::default() };
+        for (i, line) in contents.lines().enumerate() {
+            let text = line.split('#').next().unwrap_or("").trim();
+            if text.is_empty() {
+                continue;
+            }
+            builder.add(&Directive::parse(i + 1, text)?)?;
+        }
+        builder.finish()
+    }
+}
+
+impl FromStr for Scene {
+    type Err = SceneError;
+
+    // Files the scene refers to are found relative to the current directory
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        Self::parse(contents, None)
+    }
+}
+
+// One line of a scene file, already split into its name and `key=value` pairs
+struct Directive<'a> {
+    line: usize,
+    name: &'a str,
+    pairs: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Directive<'a> {
+    fn parse(line: usize, text: &'a str) -> Result<Directive<'a>, SceneError> {
+        let mut words = text.split_whitespace();
+        let name = words.next().unwrap_or("");
+        let pairs = words
+            .map(|word| word.split_once('=').ok_or_else(|| SceneError {
+                line: Some(line),
+                message: format!("expected `key=value` but found `{}`", word),
+            }))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Directive { line, name, pairs })
+    }
+
+    fn error(&self, message: String) -> SceneError {
+        SceneError { line: Some(self.line), message }
+    }
+
+    fn unknown_key(&self, key: &str) -> SceneError {
+        self.error(format!("unknown key `{}` for {}", key, self.name))
+    }
+
+    fn get(&self, key: &str) -> Option<&'a str> {
+        self.pairs.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+    }
+
+    fn require(&self, key: &str) -> Result<&'a str, SceneError> {
+        self.get(key).ok_or_else(|| self.error(format!("{} needs a `{}`", self.name, key)))
+    }
+
+    fn float(&self, key: &str, value: &str) -> Result<Float, SceneError> {
+        value.parse::<Float>()
+            .map_err(|_| self.error(format!("`{}` expects a number but found `{}`", key, value)))
+    }
+
+    fn usize(&self, key: &str, value: &str) -> Result<usize, SceneError> {
+        value.parse::<usize>()
+            .map_err(|_| self.error(format!("`{}` expects a whole number but found `{}`", key, value)))
+    }
+
+    fn floats<const N: usize>(&self, key: &str, value: &str) -> Result<[Float; N], SceneError> {
+        let pieces: Vec<&str> = value.split(',').collect();
+        if pieces.len() != N {
+            return Err(self.error(format!("`{}` expects {} comma-separated numbers but found `{}`", key, N, value)));
+        }
+        let mut numbers = [0.0; N];
+        for (n, piece) in numbers.iter_mut().zip(pieces) {
+            *n = self.float(key, piece.trim())?;
+        }
+        Ok(numbers)
+    }
+
+    fn point(&self, key: &str, value: &str) -> Result<Tuple, SceneError> {
+        let [x, y, z] = self.floats(key, value)?;
+        Ok(Tuple::new_point(x, y, z))
+    }
+
+    fn vector(&self, key: &str, value: &str) -> Result<Tuple, SceneError> {
+        let [x, y, z] = self.floats(key, value)?;
+        Ok(Tuple::new_vector(x, y, z))
+    }
+
+    fn color(&self, key: &str, value: &str) -> Result<Color, SceneError> {
+        let [r, g, b] = self.floats(key, value)?;
+        Ok(Color::new(r, g, b))
+    }
+
+    // For cylinders and cones: `minimum`, `maximum` and `closed`, defaulting to an open shape
+    // that goes on forever
+    fn truncation(&self) -> Result<(Float, Float, bool), SceneError> {
+        let minimum = match self.get("minimum") {
+            Some(value) => self.float("minimum", value)?,
+            None => Float::NEG_INFINITY,
+        };
+        let maximum = match self.get("maximum") {
+            Some(value) => self.float("maximum", value)?,
+            None => Float::INFINITY,
+        };
+        let closed = match self.get("closed") {
+            Some("true") => true,
+            Some("false") | None => false,
+            Some(value) => return Err(self.error(format!("`closed` expects true or false but found `{}`", value))),
+        };
+        Ok((minimum, maximum, closed))
+    }
+
+    // Returns false if the key isn't a material key, so the caller can try something else
+    fn apply_material(&self, material: &mut Material, key: &str, value: &str) -> Result<bool, SceneError> {
+        match key {
+            "color" => material.color = self.color(key, value)?,
+            "ambient" => material.ambient = self.float(key, value)?,
+            "diffuse" => material.diffuse = self.float(key, value)?,
+            "specular" => material.specular = self.float(key, value)?,
+            "shininess" => material.shininess = self.float(key, value)?,
+            "reflective" => material.reflective = self.float(key, value)?,
+            "transparency" => material.transparency = self.float(key, value)?,
+            "refractive_index" => material.refractive_index = self.float(key, value)?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    // Returns None if the key isn't a transform key
+    fn apply_transform(&self, transform: Matrix4, key: &str, value: &str) -> Result<Option<Matrix4>, SceneError> {
+        let t = match key {
+            "translate" => {
+                let [x, y, z] = self.floats(key, value)?;
+                transform.translate(x, y, z)
+            },
+            "scale" => {
+                let [x, y, z] = self.floats(key, value)?;
+                transform.scale(x, y, z)
+            },
+            "rotate_x" => transform.rotate_x(self.float(key, value)?),
+            "rotate_y" => transform.rotate_y(self.float(key, value)?),
+            "rotate_z" => transform.rotate_z(self.float(key, value)?),
+            "shear" => {
+                let [xy, xz, yx, yz, zx, zy] = self.floats(key, value)?;
+                transform.skew(xy, xz, yx, yz, zx, zy)
+            },
+            _ => return Ok(None),
+        };
+        Ok(Some(t))
+    }
+}
+
+const TRUNCATION_KEYS: [&str; 3] = ["minimum", "maximum", "closed"];
+
+#[derive(Default)]
+struct SceneBuilder {
+    camera: Option<Camera>,
+    lights: Vec<Light>,
+    materials: Vec<(String, Material)>,
+    objects: Vec<Box<dyn Intersectable>>,
+    base_dir: Option<PathBuf>,
+}
+
+impl SceneBuilder {
+    fn add(&mut self, directive: &Directive) -> Result<(), SceneError> {
+        match directive.name {
+            "camera" => self.camera(directive),
+            "light" => self.light(directive),
+            "material" => self.material(directive),
+            "sphere" => {
+                let center = directive.get("center").map_or(Ok(Tuple::origin_point()), |v| directive.point("center", v))?;
+                let radius = directive.get("radius").map_or(Ok(1.0), |v| directive.float("radius", v))?;
+                if radius <= 0.0 {
+                    return Err(directive.error(format!("`radius` expects a number above 0 but found `{}`", radius)));
+                }
+                self.shape(directive, Box::new(Sphere::new(center, radius)), &["center", "radius"])
+            },
+            "plane" => self.shape(directive, Box::new(Plane::new()), &[]),
+            "cube" => self.shape(directive, Box::new(Cube::new()), &[]),
+            "cylinder" => {
+                let (minimum, maximum, closed) = directive.truncation()?;
+                self.shape(directive, Box::new(Cylinder::new_truncated(minimum, maximum, closed)), &TRUNCATION_KEYS)
+            },
+            "cone" => {
+                let (minimum, maximum, closed) = directive.truncation()?;
+                self.shape(directive, Box::new(Cone::new_truncated(minimum, maximum, closed)), &TRUNCATION_KEYS)
+            },
+            "torus" => {
+                let major_radius = directive.get("major_radius").map_or(Ok(1.0), |v| directive.float("major_radius", v))?;
+                let minor_radius = directive.get("minor_radius").map_or(Ok(0.25), |v| directive.float("minor_radius", v))?;
+                if major_radius <= 0.0 || minor_radius <= 0.0 {
+                    return Err(directive.error("a torus needs both radii above 0".to_string()));
+                }
+                if minor_radius >= major_radius {
+                    return Err(directive.error(format!(
+                        "`minor_radius` has to be less than `major_radius` ({}) but found `{}`", major_radius, minor_radius,
+                    )));
+                }
+                self.shape(directive, Box::new(Torus::new(major_radius, minor_radius)), &["major_radius", "minor_radius"])
+            },
+            "quadric" => self.quadric(directive),
+            "triangle" => {
+                let p1 = directive.point("p1", directive.require("p1")?)?;
+                let p2 = directive.point("p2", directive.require("p2")?)?;
+                let p3 = directive.point("p3", directive.require("p3")?)?;
+                if !Triangle::has_area(&p1, &p2, &p3) {
+                    return Err(directive.error("a triangle's corners can't all be in a line".to_string()));
+                }
+                self.shape(directive, Box::new(Triangle::new(p1, p2, p3)), &["p1", "p2", "p3"])
+            },
+            "obj" => self.obj(directive),
+            "csg" => self.csg(directive),
+            other => Err(directive.error(format!("unknown directive `{}`", other))),
+        }
+    }
+
+    fn camera(&mut self, d: &Directive) -> Result<(), SceneError> {
+        if self.camera.is_some() {
+            return Err(d.error("a scene can only have one camera".to_string()));
+        }
+        let mut from = Tuple::new_point(0.0, 0.0, -5.0);
+        let mut to = Tuple::origin_point();
+        let mut up = Tuple::new_vector(0.0, 1.0, 0.0);
+        let mut fov = PI / 3.0;
+        for (key, value) in &d.pairs {
+            match *key {
+                "width" | "height" => (),
+                "fov" => fov = d.float(key, value)?,
+                "from" => from = d.point(key, value)?,
+                "to" => to = d.point(key, value)?,
+                "up" => up = d.vector(key, value)?,
+                _ => return Err(d.unknown_key(key)),
+            }
+        }
+        let width = d.usize("width", d.require("width")?)?;
+        let height = d.usize("height", d.require("height")?)?;
+        let mut camera = Camera::new(width, height, fov);
+        let view = Matrix4::view_transform(&from, &to, &up)
+            .map_err(|e| d.error(format!("can't point the camera that way: {}", e)))?;
+        camera.set_transform(view).map_err(|e| d.error(e.to_string()))?;
+        self.camera = Some(camera);
+        Ok(())
+    }
+
+    fn light(&mut self, d: &Directive) -> Result<(), SceneError> {
+        let mut intensity = Color::white(1.0);
+        for (key, value) in &d.pairs {
+            match *key {
+                "position" => (),
+                "intensity" => intensity = d.color(key, value)?,
+                _ => return Err(d.unknown_key(key)),
+            }
+        }
+        let position = d.point("position", d.require("position")?)?;
+        self.lights.push(Light::new_point(position, intensity));
+        Ok(())
+    }
+
+    fn material(&mut self, d: &Directive) -> Result<(), SceneError> {
+        let name = d.require("name")?;
+        let mut material = Material::default();
+        for (key, value) in &d.pairs {
+            if *key != "name" && !d.apply_material(&mut material, key, value)? {
+                return Err(d.unknown_key(key));
+            }
+        }
+        self.materials.retain(|(n, _)| n != name);
+        self.materials.push((name.to_string(), material));
+        Ok(())
+    }
+
+    // `own_keys` are the ones the caller already used to make the shape
+    fn shape(&mut self, d: &Directive, mut object: Box<dyn Intersectable>, own_keys: &[&str]) -> Result<(), SceneError> {
+        let (material, transform) = self.appearance(d, own_keys)?;
+        *object.mut_material() = material;
+        object.set_transform(transform)
+            .map_err(|e| d.error(format!("can't use that transform: {}", e)))?;
+        self.objects.push(object);
+        Ok(())
+    }
+
+    // The material and transform for a shape line
+    fn appearance(&self, d: &Directive, own_keys: &[&str]) -> Result<(Material, Matrix4), SceneError> {
+        let mut material = match d.get("material") {
+            Some(name) => self.materials.iter().find(|(n, _)| n == name)
+                .map(|(_, material)| material.clone())
+                .ok_or_else(|| d.error(format!("no material named `{}` (materials must be defined before they're used)", name)))?,
+            None => Material::default(),
+        };
+        let mut transform = Matrix4::identity();
+        for (key, value) in &d.pairs {
+            if *key == "material" || own_keys.contains(key) || d.apply_material(&mut material, key, value)? {
+                continue;
+            }
+            match d.apply_transform(transform, key, value)? {
+                Some(t) => transform = t,
+                None => return Err(d.unknown_key(key)),
+            }
+        }
+        Ok((material, transform))
+    }
+
+    // Either one of the named quadrics as `kind` or all ten `coefficients`
+    fn quadric(&mut self, d: &Directive) -> Result<(), SceneError> {
+        let quadric = match (d.get("kind"), d.get("coefficients")) {
+            (Some("paraboloid"), None) => Quadric::paraboloid(),
+            (Some("hyperboloid"), None) => Quadric::hyperboloid_one_sheet(),
+            (Some("two_sheet_hyperboloid"), None) => Quadric::hyperboloid_two_sheets(),
+            (Some(other), None) => return Err(d.error(format!("`kind` expects paraboloid, hyperboloid or two_sheet_hyperboloid but found `{}`", other))),
+            (None, Some(value)) => Quadric::new(d.floats("coefficients", value)?),
+            (Some(_), Some(_)) => return Err(d.error("quadric takes a `kind` or `coefficients`, not both".to_string())),
+            (None, None) => return Err(d.error("quadric needs a `kind` or `coefficients`".to_string())),
+        };
+        let (minimum, maximum, closed) = d.truncation()?;
+        let quadric = quadric.truncated(minimum, maximum, closed);
+        self.shape(d, Box::new(quadric), &["kind", "coefficients", "minimum", "maximum", "closed"])
+    }
+
+    // The mesh becomes one group, which takes the line's transform; every triangle in it gets
+    // the line's material. The group is divided into boxes of at most `threshold` shapes.
+    fn obj(&mut self, d: &Directive) -> Result<(), SceneError> {
+        let file = d.require("file")?;
+        let threshold = match d.get("threshold") {
+            Some(value) => d.usize("threshold", value)?,
+            None => DEFAULT_DIVIDE_THRESHOLD,
+        };
+        let path = match &self.base_dir {
+            Some(dir) => dir.join(file),
+            None => PathBuf::from(file),
+        };
+        let (material, transform) = self.appearance(d, &["file", "threshold"])?;
+        let mut obj = ObjFile::load(&path)
+            .map_err(|e| d.error(format!("can't read `{}`: {}", path.display(), e)))?;
+        let named = obj.groups.iter_mut().flat_map(|(_, shapes)| shapes.iter_mut());
+        for shape in obj.default_group.iter_mut().chain(named) {
+            *shape.mut_material() = material.clone();
+        }
+        let mut group = obj.into_group();
+        group.divide(threshold);
+        group.set_transform(transform)
+            .map_err(|e| d.error(format!("can't use that transform: {}", e)))?;
+        self.objects.push(Box::new(group));
+        Ok(())
+    }
+
+    fn csg(&mut self, d: &Directive) -> Result<(), SceneError> {
+        let operation = match d.require("operation")? {
+            "union" => CsgOperation::Union,
+            "intersection" => CsgOperation::Intersection,
+            "difference" => CsgOperation::Difference,
+            other => return Err(d.error(format!("`operation` expects union, intersection or difference but found `{}`", other))),
+        };
+        let mut transform = Matrix4::identity();
+        for (key, value) in &d.pairs {
+            if *key == "operation" {
+                continue;
+            }
+            match d.apply_transform(transform, key, value)? {
+                Some(t) => transform = t,
+                None => return Err(d.unknown_key(key)),
+            }
+        }
+        if self.objects.len() < 2 {
+            return Err(d.error("csg needs two shapes before it".to_string()));
+        }
+        let right = self.objects.pop().unwrap();
+        let left = self.objects.pop().unwrap();
+        let mut csg = Csg::new(operation, left, right);
+        csg.set_transform(transform)
+            .map_err(|e| d.error(format!("can't use that transform: {}", e)))?;
+        self.objects.push(Box::new(csg));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Scene, SceneError> {
+        let camera = self.camera.ok_or_else(|| SceneError { line: None, message: "the scene has no camera".to_string() })?;
+        Ok(Scene { camera, world: World::new(self.lights, self.objects) })
+    }
+}
@@ -0,0 +1,345 @@
+use std::fmt::Debug;
+
+use crate::{
+    bounds::BoundingBox,
+    ray::Ray,
+    shape::Shape,
+    tuple::{Point, Tuple, Vector},
+    util::{Float, EPSILON}, intersectable::Intersectable, intersection::Intersection,
+};
+
+// How far every point is from a surface: positive outside, negative inside. It doesn't have to
+// be exact (smooth blends and fractals usually aren't), but it must never be more than the
+// real distance or rays will step through the surface.
+// Fields are shared between render threads, hence `Send + Sync`.
+pub trait DistanceField: Debug + Send + Sync {
+    fn distance(&self, point: &Point) -> Float;
+
+    // A box the surface fits in, if there is one; rays are only marched inside it
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::infinite()
+    }
+}
+
+// How many steps a ray can take before giving up (grazing rays take the most)
+pub const DEFAULT_MAX_STEPS: usize = 512;
+// Steps never get smaller than this, and crossings are narrowed down to within it. It needs to
+// be well below `EPSILON`, which is how far rays start from surfaces they've just left.
+pub const DEFAULT_EPSILON: Float = EPSILON / 10.0;
+// How far to march when the field has no bounds
+pub const DEFAULT_MAX_DISTANCE: Float = 1000.0;
+
+// A shape drawn by sphere tracing a distance field. From a point on the ray, nothing is closer
+// than the field's distance, so the ray can step that far without going through the surface.
+// The surface is where the distance changes sign, so this finds the way out of the shape as
+// well as the way in (which refraction and CSG need).
+#[derive(Debug)]
+pub struct Sdf {
+    shape: Shape,
+    field: Box<dyn DistanceField>,
+    pub max_steps: usize,
+    pub epsilon: Float,
+    pub max_distance: Float,
+}
+
+impl Sdf {
+    pub fn new<F: DistanceField + 'static>(field: F) -> Sdf {
+        Sdf {
+            shape: Shape::new(),
+            field: Box::new(field),
+            max_steps: DEFAULT_MAX_STEPS,
+            epsilon: DEFAULT_EPSILON,
+            max_distance: DEFAULT_MAX_DISTANCE,
+        }
+    }
+
+    pub fn field(&self) -> &dyn DistanceField {
+        self.field.as_ref()
+    }
+
+    // Halves the step that crossed the surface until it's shorter than `epsilon`. The step can go
+    // either way along the ray.
+    fn narrow_crossing(&self, ray: &Ray, mut before: Float, mut after: Float, outside: bool, speed: Float) -> Float {
+        while (after - before).abs() * speed > self.epsilon {
+            let middle = (before + after) / 2.0;
+            if (self.field.distance(&ray.position(&middle)) > 0.0) == outside {
+                before = middle;
+            } else {
+                after = middle;
+            }
+        }
+        (before + after) / 2.0
+    }
+
+    // Steps back along the ray from `t`, which is inside the shape, to where the ray went in
+    fn entry_behind(&self, ray: &Ray, mut t: Float, mut distance: Float, speed: Float) -> Option<Float> {
+        let start = t - self.max_distance / speed;
+        for _ in 0..self.max_steps {
+            if t < start {
+                break;
+            }
+            let previous = t - distance.abs().max(self.epsilon) / speed;
+            let previous_distance = self.field.distance(&ray.position(&previous));
+            if previous_distance > 0.0 {
+                return Some(self.narrow_crossing(ray, t, previous, false, speed));
+            }
+            t = previous;
+            distance = previous_distance;
+        }
+        None
+    }
+}
+
+impl Intersectable for Sdf {
+    fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn mut_shape(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    // Like the analytic shapes, this gives every hit along the ray's line (behind the origin as
+    // well), as long as the field has bounds to start from. Without them marching starts at the
+    // origin, so the only hit behind it is the way into whatever the origin is inside.
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        // a little room around the box, so marching never starts right on the surface
+        let Some((enter, exit)) = self.field.bounds().expand(10.0 * self.epsilon).entry_exit(ray) else {
+            return vec![];
+        };
+        // t is in units of the ray's direction, which transforms can stretch
+        let speed = ray.direction.magnitude().unwrap();
+        let mut t = if enter.is_finite() { enter } else { 0.0 };
+        let end = if exit.is_finite() { exit } else { t + self.max_distance / speed };
+
+        let mut xs = vec![];
+        let mut distance = self.field.distance(&ray.position(&t));
+        // Without bounds the ray can start inside (refracted rays always do), and the way in is
+        // behind it. If that can't be found the way out has nothing to pair with.
+        let mut unpaired_exit = false;
+        if distance <= 0.0 {
+            match self.entry_behind(ray, t, distance, speed) {
+                Some(entry) => xs.push(Intersection::new(entry, self)),
+                None => unpaired_exit = true,
+            }
+        }
+        for _ in 0..self.max_steps {
+            if t > end {
+                break;
+            }
+            let next = t + distance.abs().max(self.epsilon) / speed;
+            let next_distance = self.field.distance(&ray.position(&next));
+            if (next_distance > 0.0) != (distance > 0.0) {
+                xs.push(Intersection::new(self.narrow_crossing(ray, t, next, distance > 0.0, speed), self));
+            }
+            t = next;
+            distance = next_distance;
+        }
+        // Running out of steps (or distance) inside the shape means the last crossing went in
+        // and never came out. Hits have to pair up for CSG and refraction, so it's dropped.
+        if distance <= 0.0 {
+            xs.pop();
+        }
+        if unpaired_exit && !xs.is_empty() {
+            xs.remove(0);
+        }
+        xs
+    }
+
+    // Central differences: how quickly the distance changes along each axis
+    fn local_normal_at(&self, point: &Point) -> Vector {
+        let h = self.epsilon;
+        let slope = |offset: Vector| self.field.distance(&(point + offset)) - self.field.distance(&(point - offset));
+        Tuple::new_vector(
+            slope(Tuple::new_vector(h, 0.0, 0.0)),
+            slope(Tuple::new_vector(0.0, h, 0.0)),
+            slope(Tuple::new_vector(0.0, 0.0, h)),
+        )
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        self.field.bounds()
+    }
+}
+
+fn length(x: Float, y: Float, z: Float) -> Float {
+    (x * x + y * y + z * z).sqrt()
+}
+
+fn mix(a: Float, b: Float, fraction: Float) -> Float {
+    a + (b - a) * fraction
+}
+
+// A sphere around the origin
+#[derive(Debug, Clone)]
+pub struct SphereField {
+    pub radius: Float,
+}
+
+impl DistanceField for SphereField {
+    fn distance(&self, point: &Point) -> Float {
+        length(point.x, point.y, point.z) - self.radius
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let r = self.radius;
+        BoundingBox::new(Tuple::new_point(-r, -r, -r), Tuple::new_point(r, r, r))
+    }
+}
+
+// A box around the origin, reaching `half_size` out along each axis
+#[derive(Debug, Clone)]
+pub struct BoxField {
+    pub half_size: Vector,
+}
+
+impl DistanceField for BoxField {
+    fn distance(&self, point: &Point) -> Float {
+        let q = Tuple::new_vector(point.x.abs() - self.half_size.x, point.y.abs() - self.half_size.y, point.z.abs() - self.half_size.z);
+        let outside = length(q.x.max(0.0), q.y.max(0.0), q.z.max(0.0));
+        let inside = q.x.max(q.y).max(q.z).min(0.0);
+        outside + inside
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(Tuple::origin_point() - self.half_size, Tuple::origin_point() + self.half_size)
+    }
+}
+
+// A box the same size as `BoxField`, with its edges and corners rounded off by `radius`
+#[derive(Debug, Clone)]
+pub struct RoundedBoxField {
+    pub half_size: Vector,
+    pub radius: Float,
+}
+
+impl DistanceField for RoundedBoxField {
+    fn distance(&self, point: &Point) -> Float {
+        let inner = Tuple::new_vector(self.half_size.x - self.radius, self.half_size.y - self.radius, self.half_size.z - self.radius);
+        BoxField { half_size: inner }.distance(point) - self.radius
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoxField { half_size: self.half_size }.bounds()
+    }
+}
+
+// A ring lying in the xz-plane, like `Torus`
+#[derive(Debug, Clone)]
+pub struct TorusField {
+    pub major_radius: Float,
+    pub minor_radius: Float,
+}
+
+impl DistanceField for TorusField {
+    fn distance(&self, point: &Point) -> Float {
+        let from_ring = (point.x * point.x + point.z * point.z).sqrt() - self.major_radius;
+        (from_ring * from_ring + point.y * point.y).sqrt() - self.minor_radius
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let outer = self.major_radius + self.minor_radius;
+        BoundingBox::new(
+            Tuple::new_point(-outer, -self.minor_radius, -outer),
+            Tuple::new_point(outer, self.minor_radius, outer),
+        )
+    }
+}
+
+// Both fields, with the crease where they meet filled in over a distance of about `k` so they
+// flow into each other like blobs. A `k` of 0 is a plain union.
+#[derive(Debug)]
+pub struct SmoothUnion {
+    pub a: Box<dyn DistanceField>,
+    pub b: Box<dyn DistanceField>,
+    pub k: Float,
+}
+
+impl SmoothUnion {
+    pub fn new(a: impl DistanceField + 'static, b: impl DistanceField + 'static, k: Float) -> SmoothUnion {
+        SmoothUnion { a: Box::new(a), b: Box::new(b), k }
+    }
+}
+
+impl DistanceField for SmoothUnion {
+    fn distance(&self, point: &Point) -> Float {
+        let (da, db) = (self.a.distance(point), self.b.distance(point));
+        if self.k <= 0.0 {
+            return da.min(db);
+        }
+        let h = (0.5 + 0.5 * (db - da) / self.k).clamp(0.0, 1.0);
+        mix(db, da, h) - self.k * h * (1.0 - h)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = self.a.bounds();
+        bounds.merge(&self.b.bounds());
+        bounds.expand(self.k)
+    }
+}
+
+// `a` with `b` cut out of it, with the edge of the cut rounded over a distance of about `k`.
+// A `k` of 0 is a plain difference.
+#[derive(Debug)]
+pub struct SmoothSubtraction {
+    pub a: Box<dyn DistanceField>,
+    pub b: Box<dyn DistanceField>,
+    pub k: Float,
+}
+
+impl SmoothSubtraction {
+    pub fn new(a: impl DistanceField + 'static, b: impl DistanceField + 'static, k: Float) -> SmoothSubtraction {
+        SmoothSubtraction { a: Box::new(a), b: Box::new(b), k }
+    }
+}
+
+impl DistanceField for SmoothSubtraction {
+    fn distance(&self, point: &Point) -> Float {
+        let (da, db) = (self.a.distance(point), self.b.distance(point));
+        if self.k <= 0.0 {
+            return da.max(-db);
+        }
+        let h = (0.5 - 0.5 * (da + db) / self.k).clamp(0.0, 1.0);
+        mix(da, -db, h) + self.k * h * (1.0 - h)
+    }
+
+    // cutting can only make it smaller
+    fn bounds(&self) -> BoundingBox {
+        self.a.bounds()
+    }
+}
+
+// Copies of the field every `period` along each axis, forever; a period of 0 leaves that axis
+// alone. The field should fit inside one period or the copies get cut off.
+#[derive(Debug)]
+pub struct Repeat {
+    pub field: Box<dyn DistanceField>,
+    pub period: Vector,
+}
+
+impl Repeat {
+    pub fn new(field: impl DistanceField + 'static, period: Vector) -> Repeat {
+        Repeat { field: Box::new(field), period }
+    }
+}
+
+impl DistanceField for Repeat {
+    fn distance(&self, point: &Point) -> Float {
+        let wrap = |x: Float, period: Float| if period > 0.0 { x - period * (x / period).round() } else { x };
+        let local = Tuple::new_point(wrap(point.x, self.period.x), wrap(point.y, self.period.y), wrap(point.z, self.period.z));
+        self.field.distance(&local)
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let inner = self.field.bounds();
+        let axis = |min: Float, max: Float, period: Float| if period > 0.0 { (Float::NEG_INFINITY, Float::INFINITY) } else { (min, max) };
+        let (min_x, max_x) = axis(inner.min.x, inner.max.x, self.period.x);
+        let (min_y, max_y) = axis(inner.min.y, inner.max.y, self.period.y);
+        let (min_z, max_z) = axis(inner.min.z, inner.max.z, self.period.z);
+        BoundingBox::new(Tuple::new_point(min_x, min_y, min_z), Tuple::new_point(max_x, max_y, max_z))
+    }
+}
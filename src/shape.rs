@@ -0,0 +1,88 @@
+use crate::{material::Material, matrix::NotInvertibleError, matrix4::Matrix4};
+
+// The state that every kind of shape carries: where it sits in the world and what it's made of.
+// The inverse and inverse-transpose are needed for every ray and every normal, so they're
+// worked out once whenever the transform changes rather than every time they're used.
+// A shape inside a `Group` also keeps the inverse of everything its groups do to it
+// (`parent_inverse`), so normals and patterns can go straight between world space and object
+// space without walking back up through the groups.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Shape {
+    transform: Matrix4,
+    inverse: Matrix4,
+    inverse_transpose: Matrix4,
+    parent_inverse: Matrix4,
+    world_inverse: Matrix4,
+    world_inverse_transpose: Matrix4,
+    pub material: Material,
+}
+
+impl Default for Shape {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shape {
+    pub fn new() -> Shape {
+        Shape {
+            transform: Matrix4::identity(),
+            inverse: Matrix4::identity(),
+            inverse_transpose: Matrix4::identity(),
+            parent_inverse: Matrix4::identity(),
+            world_inverse: Matrix4::identity(),
+            world_inverse_transpose: Matrix4::identity(),
+            material: Material::default(),
+        }
+    }
+
+    pub fn transformation(&self) -> &Matrix4 {
+        &self.transform
+    }
+
+    pub fn inverse(&self) -> &Matrix4 {
+        &self.inverse
+    }
+
+    pub fn inverse_transpose(&self) -> &Matrix4 {
+        &self.inverse_transpose
+    }
+
+    // Takes world space to the parent group's object space (identity outside of any group)
+    pub fn parent_inverse(&self) -> &Matrix4 {
+        &self.parent_inverse
+    }
+
+    // Takes world space all the way to this shape's object space
+    // In book: world_to_object(shape, point) does this one group at a time
+    pub fn world_inverse(&self) -> &Matrix4 {
+        &self.world_inverse
+    }
+
+    // In book: normal_to_world(shape, normal) does this one group at a time
+    pub fn world_inverse_transpose(&self) -> &Matrix4 {
+        &self.world_inverse_transpose
+    }
+
+    // A transform that can't be inverted can't be used to find where rays hit, so it's
+    // rejected here and the shape keeps its old transform
+    pub fn set_transform(&mut self, trans: Matrix4) -> Result<(), NotInvertibleError> {
+        let inverse = trans.inverse()?;
+        self.inverse_transpose = inverse.transpose();
+        self.inverse = inverse;
+        self.transform = trans;
+        self.update_world_inverse();
+        Ok(())
+    }
+
+    pub fn set_parent_inverse(&mut self, parent_inverse: Matrix4) -> &Self {
+        self.parent_inverse = parent_inverse;
+        self.update_world_inverse();
+        self
+    }
+
+    fn update_world_inverse(&mut self) {
+        self.world_inverse = self.inverse * self.parent_inverse;
+        self.world_inverse_transpose = self.world_inverse.transpose();
+    }
+}
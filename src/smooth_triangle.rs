@@ -0,0 +1,91 @@
+use crate::{
+    bounds::BoundingBox,
+    ray::Ray,
+    shape::Shape,
+    tuple::{Point, Vector},
+    intersectable::Intersectable, intersection::Intersection, triangle::moller_trumbore,
+};
+
+// A triangle with a normal at each corner. The normal anywhere else is blended from those
+// three, so a mesh of these looks curved even though each piece is flat.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SmoothTriangle {
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    n1: Vector,
+    n2: Vector,
+    n3: Vector,
+    e1: Vector,
+    e2: Vector,
+    shape: Shape,
+}
+
+impl SmoothTriangle {
+    // pg 221
+    // In book: smooth_triangle(p1, p2, p3, n1, n2, n3)
+    pub fn new(p1: Point, p2: Point, p3: Point, n1: Vector, n2: Vector, n3: Vector) -> SmoothTriangle {
+        SmoothTriangle { p1, p2, p3, n1, n2, n3, e1: p2 - p1, e2: p3 - p1, shape: Shape::new() }
+    }
+
+    pub fn p1(&self) -> &Point {
+        &self.p1
+    }
+    pub fn p2(&self) -> &Point {
+        &self.p2
+    }
+    pub fn p3(&self) -> &Point {
+        &self.p3
+    }
+    pub fn n1(&self) -> &Vector {
+        &self.n1
+    }
+    pub fn n2(&self) -> &Vector {
+        &self.n2
+    }
+    pub fn n3(&self) -> &Vector {
+        &self.n3
+    }
+}
+
+impl Intersectable for SmoothTriangle {
+    fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn mut_shape(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        match moller_trumbore(&self.p1, &self.e1, &self.e2, ray) {
+            Some((t, u, v)) => vec![Intersection::new_with_uv(t, self, u, v)],
+            None => vec![],
+        }
+    }
+
+    // Without a hit there's nothing to blend with, so this is the normal at p1
+    fn local_normal_at(&self, _point: &Point) -> Vector {
+        self.n1
+    }
+
+    // pg 222
+    fn local_normal_at_hit(&self, point: &Point, hit: &Intersection) -> Vector {
+        match hit.uv {
+            Some((u, v)) => self.n2 * u + self.n3 * v + self.n1 * (1.0 - u - v),
+            None => self.local_normal_at(point),
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::empty();
+        for p in [&self.p1, &self.p2, &self.p3] {
+            bounds.add_point(p);
+        }
+        bounds
+    }
+}
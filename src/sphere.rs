@@ -1,19 +1,18 @@
-use core::panic;
-
 use crate::{
-    material::Material,
-    matrix::Matrix,
+    bounds::BoundingBox,
     ray::Ray,
+    shape::Shape,
     tuple::{Point, Tuple, Vector},
     util::Float, intersectable::Intersectable, intersection::Intersection,
 };
 
+// A sphere of `radius` around `origin` in object space. The book only uses the unit sphere and
+// moves and sizes it with the transform; both can be used together.
 #[derive(Debug, PartialEq, Clone)]
 pub struct Sphere {
     pub origin: Tuple,
     pub radius: Float,
-    pub transform: Matrix,
-    pub material: Material,
+    shape: Shape,
 }
 
 #[derive(Debug, PartialEq)]
@@ -26,92 +25,68 @@ struct Discriminant {
 }
 
 impl Sphere {
+    // The radius has to be above 0: a sphere with none has no normals, and a negative one would
+    // be inside out
+    pub fn new(origin: Point, radius: Float) -> Sphere {
+        assert!(radius > 0.0, "a sphere's radius has to be above 0 but was {}", radius);
+        Sphere { origin, radius, shape: Shape::new() }
+    }
+
     pub fn new_unit() -> Sphere {
-        Sphere {
-            origin: Tuple::new_point(0.0, 0.0, 0.0),
-            radius: 1.0,
-            transform: Matrix::identity(4),
-            material: Material::default(),
-        }
+        Sphere::new(Tuple::new_point(0.0, 0.0, 0.0), 1.0)
+    }
+
+    // pg 151
+    // In book: glass_sphere()
+    pub fn glass() -> Sphere {
+        let mut s = Sphere::new_unit();
+        s.shape.material.transparency = 1.0;
+        s.shape.material.refractive_index = 1.5;
+        s
     }
 
     fn discriminant(&self, ray: &Ray) -> Discriminant {
         let sphere_to_ray = ray.origin - self.origin;
         let a = ray.direction.dot(&ray.direction);
         let b = 2.0 * ray.direction.dot(&sphere_to_ray);
-        let c = sphere_to_ray.dot(&sphere_to_ray) - 1.0;
+        let c = sphere_to_ray.dot(&sphere_to_ray) - self.radius.powi(2);
         let d = b.powi(2) - 4.0 * a * c;
-        // eprintln!("a={}; b={}; c={}; d={}", a, b, c, d);
         Discriminant { a, b, c, d }
     }
-    pub fn set_transform(&mut self, trans: Matrix) -> &Self {
-        self.transform = trans;
-        self
-    }
-    fn transformed_ray(&self, ray: &Ray) -> Ray {
-        &self.transform.inverse().unwrap() * ray // todo: avoid unwrap
-    }
 }
 
 impl Intersectable for Sphere {
-    fn is_intersecting(&self, ray: &Ray) -> bool {
-        self.discriminant(&self.transformed_ray(ray)).d >= 0.0
+    fn shape(&self) -> &Shape {
+        &self.shape
     }
 
-    fn intersection_count(&self, ray: &Ray) -> usize {
-        match self.discriminant(&self.transformed_ray(ray)).d {
-            i if i < 0.0 => 0,
-            i if i == 0.0 => 1,
-            i if i > 0.0 => 2,
-            i if i.is_infinite() => panic!("Discriminant is Inf"),
-            i if i.is_nan() => panic!("Discriminant is NaN"),
-            i => panic!("Unknow error for discriminant `{:?}`", i),
-        }
+    fn mut_shape(&mut self) -> &mut Shape {
+        &mut self.shape
     }
 
-    fn intersections(&self, ray: &Ray) -> Vec<Intersection> {
-        let discriminant = self.discriminant(&self.transformed_ray(ray));
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let discriminant = self.discriminant(ray);
         match discriminant.d {
             d if d < 0.0 => vec![],
-            d if d == 0.0 => vec![Intersection {
-                t: -discriminant.b / (2.0 * discriminant.a),
-                object: self,
-            }],
+            0.0 => vec![Intersection::new(-discriminant.b / (2.0 * discriminant.a), self)],
             d if d > 0.0 => vec![
-                Intersection {
-                    t: (-discriminant.b - d.sqrt()) / (2.0 * discriminant.a),
-                    object: self,
-                },
-                Intersection {
-                    t: (-discriminant.b + d.sqrt()) / (2.0 * discriminant.a),
-                    object: self,
-                },
+                Intersection::new((-discriminant.b - d.sqrt()) / (2.0 * discriminant.a), self),
+                Intersection::new((-discriminant.b + d.sqrt()) / (2.0 * discriminant.a), self),
             ],
             _ => panic!("Bad discriminant `{:?}`", discriminant),
         }
     }
 
-    fn as_any(&self) -> &dyn std::any::Any {
-        self
-    }
-
-    fn transformation(&self) -> &Matrix {
-        &self.transform
+    fn local_normal_at(&self, point: &Point) -> Vector {
+        (point - self.origin) / self.radius
     }
 
-    fn surface_normal(&self, point: &Point) -> Vector {
-        let object_point = self.transform.inverse().unwrap() * point;
-        let object_normal = object_point - self.origin;
-        let transform = self.transform.inverse().unwrap().transpose();
-        let mut world_normal = transform * object_normal;
-        world_normal.w = 0.0;
-        world_normal.normalize().unwrap()
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
     }
 
-    fn mut_material(&mut self) -> &mut Material {
-        &mut self.material
-    }
-    fn material(&self) -> &Material {
-        &self.material
+    fn bounds(&self) -> BoundingBox {
+        let reach = Tuple::new_vector(self.radius, self.radius, self.radius);
+        BoundingBox::new(self.origin - reach, self.origin + reach)
     }
 }
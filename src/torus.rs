@@ -0,0 +1,84 @@
+use crate::{
+    bounds::BoundingBox,
+    ray::Ray,
+    shape::Shape,
+    polynomial,
+    tuple::{Point, Tuple, Vector},
+    util::Float, intersectable::Intersectable, intersection::Intersection,
+};
+
+// A ring (doughnut) lying flat in the xz-plane around the y axis. `major_radius` is how far
+// the middle of the tube is from the y axis and `minor_radius` is how thick the tube is.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Torus {
+    pub major_radius: Float,
+    pub minor_radius: Float,
+    shape: Shape,
+}
+
+impl Torus {
+    // Both radii should be above 0, with the tube thinner than the ring is wide. A fatter tube
+    // goes through itself and the hits no longer pair up into ways in and out.
+    pub fn new(major_radius: Float, minor_radius: Float) -> Torus {
+        Torus { major_radius, minor_radius, shape: Shape::new() }
+    }
+}
+
+impl Intersectable for Torus {
+    fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn mut_shape(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    // A point is on the torus when (x² + y² + z² + R² - r²)² = 4R²(x² + z²), which along the
+    // ray is a quartic in t. The ray is first moved along itself to the point closest to the
+    // middle of the torus, which keeps the coefficients small when the ray starts far away.
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let d = ray.direction;
+        let dd = d.dot(&d);
+        // from the middle of the torus to the ray's (moved) origin
+        let start = ray.origin - Tuple::origin_point();
+        let shift = -start.dot(&d) / dd;
+        let o = start + d * shift;
+        let (big_r2, small_r2) = (self.major_radius.powi(2), self.minor_radius.powi(2));
+
+        let od = o.dot(&d);
+        let k = o.dot(&o) + big_r2 - small_r2;
+        let four_r2 = 4.0 * big_r2;
+        polynomial::solve_quartic(
+            dd * dd,
+            4.0 * dd * od,
+            2.0 * dd * k + 4.0 * od * od - four_r2 * (d.x * d.x + d.z * d.z),
+            4.0 * k * od - 2.0 * four_r2 * (o.x * d.x + o.z * d.z),
+            k * k - four_r2 * (o.x * o.x + o.z * o.z),
+        )
+        .into_iter()
+        .map(|t| Intersection::new(t + shift, self))
+        .collect()
+    }
+
+    // Straight out from the middle of the tube
+    fn local_normal_at(&self, point: &Point) -> Vector {
+        let distance = (point.x * point.x + point.z * point.z).sqrt();
+        if distance == 0.0 {
+            return Tuple::new_vector(0.0, point.y.signum(), 0.0);
+        }
+        let scale = self.major_radius / distance;
+        Tuple::new_vector(point.x - point.x * scale, point.y, point.z - point.z * scale)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let outer = self.major_radius + self.minor_radius;
+        BoundingBox::new(
+            Tuple::new_point(-outer, -self.minor_radius, -outer),
+            Tuple::new_point(outer, self.minor_radius, outer),
+        )
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{
+    bounds::BoundingBox,
+    ray::Ray,
+    shape::Shape,
+    tuple::{Point, Vector},
+    util::{Float, EPSILON}, intersectable::Intersectable, intersection::Intersection,
+};
+
+// A flat triangle between three points. The edges and normal never change, so they're worked
+// out up front.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Triangle {
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    e1: Vector,
+    e2: Vector,
+    normal: Vector,
+    shape: Shape,
+}
+
+impl Triangle {
+    // pg 208
+    // In book: triangle(p1, p2, p3)
+    pub fn new(p1: Point, p2: Point, p3: Point) -> Triangle {
+        let e1 = p2 - p1;
+        let e2 = p3 - p1;
+        let normal = e2.cross(&e1).unwrap().normalize().unwrap();
+        Triangle { p1, p2, p3, e1, e2, normal, shape: Shape::new() }
+    }
+
+    // Corners that are the same or in a line make a triangle with no area, and so no normal
+    pub fn has_area(p1: &Point, p2: &Point, p3: &Point) -> bool {
+        let area = (*p2 - *p1).cross(&(*p3 - *p1)).unwrap().magnitude();
+        matches!(area, Ok(a) if a > 0.0)
+    }
+
+    pub fn p1(&self) -> &Point {
+        &self.p1
+    }
+    pub fn p2(&self) -> &Point {
+        &self.p2
+    }
+    pub fn p3(&self) -> &Point {
+        &self.p3
+    }
+    pub fn e1(&self) -> &Vector {
+        &self.e1
+    }
+    pub fn e2(&self) -> &Vector {
+        &self.e2
+    }
+    pub fn normal(&self) -> &Vector {
+        &self.normal
+    }
+}
+
+// pg 210
+// The Möller–Trumbore algorithm: where the ray crosses the triangle starting at p1 with edges
+// e1 and e2, as (t, u, v), where u and v say how far along each edge the hit is
+pub(crate) fn moller_trumbore(p1: &Point, e1: &Vector, e2: &Vector, ray: &Ray) -> Option<(Float, Float, Float)> {
+    let dir_cross_e2 = ray.direction.cross(e2).unwrap();
+    let det = e1.dot(&dir_cross_e2);
+    if det.abs() < EPSILON {
+        return None; // parallel to the triangle
+    }
+    let f = 1.0 / det;
+    let p1_to_origin = ray.origin - p1;
+    let u = f * p1_to_origin.dot(&dir_cross_e2);
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let origin_cross_e1 = p1_to_origin.cross(e1).unwrap();
+    let v = f * ray.direction.dot(&origin_cross_e1);
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = f * e2.dot(&origin_cross_e1);
+    Some((t, u, v))
+}
+
+impl Intersectable for Triangle {
+    fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn mut_shape(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        match moller_trumbore(&self.p1, &self.e1, &self.e2, ray) {
+            Some((t, u, v)) => vec![Intersection::new_with_uv(t, self, u, v)],
+            None => vec![],
+        }
+    }
+
+    // pg 209: flat, so the normal is the same everywhere
+    fn local_normal_at(&self, _point: &Point) -> Vector {
+        self.normal
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let mut bounds = BoundingBox::empty();
+        for p in [&self.p1, &self.p2, &self.p3] {
+            bounds.add_point(p);
+        }
+        bounds
+    }
+}
@@ -147,7 +147,7 @@ pub struct NotVector {
 }
 impl NotVector {
     pub fn new(tuple: &Tuple) -> NotVector {
-        NotVector{tuple: tuple.clone()}
+        NotVector{tuple: *tuple}
     }
 }
 impl Error for NotVector {}
@@ -1,5 +1,9 @@
 
 pub type Float = f64;
+
+// Offset used to nudge points off of a surface so they don't re-intersect it
+pub const EPSILON: Float = 0.00001;
+
 pub fn feq(a: &Float, b: &Float) -> bool {
     feq_precision(a, b, 5)
 }
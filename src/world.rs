@@ -1,28 +1,51 @@
-use crate::{light::Light, ray::Ray, tuple::Tuple, color::Color, sphere::Sphere, matrix::Matrix, intersectable::Intersectable, intersection::{Intersection, IntersectionPrecomputation}};
+use crate::{light::Light, ray::Ray, tuple::{Tuple, Point}, color::{Color, BLACK}, sphere::Sphere, matrix4::Matrix4, intersectable::Intersectable, intersection::{Intersection, IntersectionPrecomputation}, util::EPSILON};
 
 #[derive(Debug)]
 pub struct World {
-    pub light: Light,
+    pub lights: Vec<Light>,
     pub objects: Vec<Box<dyn Intersectable>>,
+    // How many times a ray may be reflected or refracted before we give up on it;
+    // without a limit, two facing mirrors would recurse forever
+    pub max_depth: usize,
 }
 
-impl World {
+// pg 148 suggests 4 or 5
+pub const DEFAULT_MAX_DEPTH: usize = 5;
+
+impl Default for World {
     // In book: default_world()
-    pub fn default() -> World {
+    fn default() -> World {
         let mut s1: Sphere = Sphere::new_unit();
-        s1.material.color = Color::new(0.8, 1.0, 0.6);
-        s1.material.diffuse = 0.7;
-        s1.material.specular = 0.2;
+        s1.mut_material().color = Color::new(0.8, 1.0, 0.6);
+        s1.mut_material().diffuse = 0.7;
+        s1.mut_material().specular = 0.2;
         let mut s2 = Sphere::new_unit();
-        s2.set_transform(Matrix::scaling(0.5, 0.5, 0.5));
-        World { 
-            light: Light::new_point(Tuple::new_point(-10.0, 10.0, -10.0), Color::white(1.0)), 
-            objects: vec![Box::new(s1), Box::new(s2)],
-        }
+        s2.set_transform(Matrix4::scaling(0.5, 0.5, 0.5)).unwrap();
+        World::new(
+            vec![Light::new_point(Tuple::new_point(-10.0, 10.0, -10.0), Color::white(1.0))],
+            vec![Box::new(s1), Box::new(s2)],
+        )
+    }
+}
+
+impl World {
+    pub fn new(lights: Vec<Light>, objects: Vec<Box<dyn Intersectable>>) -> World {
+        World { lights, objects, max_depth: DEFAULT_MAX_DEPTH }
+    }
+
+    // Convenience for the common single-light world: the first light, if any
+    pub fn light(&self) -> Option<&Light> {
+        self.lights.first()
+    }
+
+    // Replace all of the lights with just this one
+    pub fn set_light(&mut self, light: Light) -> &Self {
+        self.lights = vec![light];
+        self
     }
 
     // In book: intersect_world(world, ray)
-    pub fn intersections(&self, ray: &Ray) -> Vec<Intersection> {
+    pub fn intersections(&self, ray: &Ray) -> Vec<Intersection<'_>> {
         let mut xs: Vec<Intersection> = self.objects.iter().flat_map(|obj| obj.intersections(ray)).collect();
         xs.sort_by(|a,b| a.partial_cmp(b).unwrap());
         xs
@@ -31,20 +54,96 @@ impl World {
     // pg 96
     // In book: shade_hit(world, comps)
     pub fn shade_hit(&self, comps: &IntersectionPrecomputation) -> Color {
-        comps.object.material().light(&self.light, &comps.point, &comps.eyev, &comps.normalv)
+        self.shade_hit_remaining(comps, self.max_depth)
+    }
+
+    // pg 145
+    // In book: shade_hit(world, comps, remaining)
+    // Each light contributes its own color, shadowed independently of the others
+    pub fn shade_hit_remaining(&self, comps: &IntersectionPrecomputation, remaining: usize) -> Color {
+        let surface = self.lights.iter()
+            .map(|light| {
+                let shadowed = self.is_shadowed(light, &comps.over_point);
+                comps.object.material().light(comps.object, light, &comps.over_point, &comps.eyev, &comps.normalv, shadowed)
+            })
+            .fold(BLACK, |acc, c| acc + c);
+        let reflected = self.reflected_color(comps, remaining);
+        let refracted = self.refracted_color(comps, remaining);
+        let material = comps.object.material();
+        if material.reflective > 0.0 && material.transparency > 0.0 {
+            // pg 164: split the light between the two with the Fresnel effect
+            let reflectance = comps.schlick();
+            surface + reflected * reflectance + refracted * (1.0 - reflectance)
+        } else {
+            surface + reflected + refracted
+        }
+    }
+
+    // pg 144
+    // In book: reflected_color(world, comps, remaining)
+    pub fn reflected_color(&self, comps: &IntersectionPrecomputation, remaining: usize) -> Color {
+        let reflective = comps.object.material().reflective;
+        if remaining == 0 || reflective == 0.0 {
+            return BLACK;
+        }
+        let reflect_ray = Ray::new(comps.over_point, comps.reflectv);
+        self.color_at_remaining(&reflect_ray, remaining - 1) * reflective
+    }
+
+    // pg 155
+    // In book: refracted_color(world, comps, remaining)
+    pub fn refracted_color(&self, comps: &IntersectionPrecomputation, remaining: usize) -> Color {
+        let transparency = comps.object.material().transparency;
+        if remaining == 0 || transparency == 0.0 {
+            return BLACK;
+        }
+        // Snell's law, pg 157
+        let n_ratio = comps.n1 / comps.n2;
+        let cos_i = comps.eyev.dot(&comps.normalv);
+        let sin2_t = n_ratio.powi(2) * (1.0 - cos_i.powi(2));
+        if sin2_t > 1.0 {
+            return BLACK; // total internal reflection
+        }
+        let cos_t = (1.0 - sin2_t).sqrt();
+        let direction = comps.normalv * (n_ratio * cos_i - cos_t) - comps.eyev * n_ratio;
+        let refract_ray = Ray::new(comps.under_point, direction);
+        self.color_at_remaining(&refract_ray, remaining - 1) * transparency
+    }
+
+    // pg 111
+    // In book: is_shadowed(world, point)
+    pub fn is_shadowed(&self, light: &Light, point: &Point) -> bool {
+        let v = light.position - point;
+        // nothing can come between a point and a light that's right on it
+        let distance = match v.magnitude() {
+            Ok(distance) if distance >= EPSILON => distance,
+            _ => return false,
+        };
+        let ray = Ray::new(*point, v / distance);
+        let intersections = self.intersections(&ray);
+        match Intersection::hit(&intersections) {
+            Some(hit) => hit.t < distance,
+            None => false,
+        }
     }
 
     // pg 97
     // In book: color_at(w, r)
     pub fn color_at(&self, ray: &Ray) -> Color {
+        self.color_at_remaining(ray, self.max_depth)
+    }
+
+    // pg 145
+    // In book: color_at(w, r, remaining)
+    pub fn color_at_remaining(&self, ray: &Ray, remaining: usize) -> Color {
         let intersections = self.intersections(ray);
         let maybe_hit = Intersection::hit(&intersections);
         match maybe_hit {
             None => 
                 Color::white(0.0),
             Some(hit) => {
-                let precomp = hit.precompute(ray);
-                self.shade_hit(&precomp)
+                let precomp = hit.precompute_with_intersections(ray, &intersections);
+                self.shade_hit_remaining(&precomp, remaining)
             }
         }
     }
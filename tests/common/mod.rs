@@ -0,0 +1,78 @@
+// Helpers shared by the test files. Each file only uses some of them.
+#![allow(dead_code)]
+
+use std::sync::Mutex;
+
+use book_renderer::{
+    bounds::BoundingBox, shape::Shape, sphere::Sphere, matrix4::Matrix4, intersectable::Intersectable,
+    intersection::Intersection, ray::Ray, tuple::{Tuple, Point, Vector}, util::{feq, Float},
+};
+
+// A ray with its direction normalized, so hits are distances along it
+pub fn ray(origin: Tuple, direction: Tuple) -> Ray {
+    Ray::new(origin, direction.normalize().unwrap())
+}
+
+// The t of every hit, in the order the shape gave them
+pub fn ts(shape: &dyn Intersectable, r: &Ray) -> Vec<Float> {
+    shape.intersections(r).iter().map(|x| x.t).collect()
+}
+
+// A unit sphere moved to (x, y, z), ready to add to a group or CSG
+pub fn translated_sphere(x: Float, y: Float, z: Float) -> Box<dyn Intersectable> {
+    let mut s = Sphere::new_unit();
+    s.set_transform(Matrix4::translation(x, y, z)).unwrap();
+    Box::new(s)
+}
+
+pub fn assert_ts(actual: Vec<Float>, expected: &[Float]) {
+    assert_eq!(actual.len(), expected.len(), "{:?} is not {:?}", actual, expected);
+    for (a, e) in actual.iter().zip(expected) {
+        assert!(feq(a, e), "{:?} is not {:?}", actual, expected);
+    }
+}
+
+// In book: test_shape()
+// Remembers the last ray it was asked to intersect so tests can check what was passed in
+#[derive(Debug)]
+pub struct TestShape {
+    shape: Shape,
+    saved_ray: Mutex<Option<Ray>>,
+}
+
+impl TestShape {
+    pub fn new() -> TestShape {
+        TestShape { shape: Shape::new(), saved_ray: Mutex::new(None) }
+    }
+
+    pub fn saved_ray(&self) -> Option<Ray> {
+        *self.saved_ray.lock().unwrap()
+    }
+}
+
+impl Intersectable for TestShape {
+    fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    fn mut_shape(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+
+    fn local_intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        *self.saved_ray.lock().unwrap() = Some(*ray);
+        vec![]
+    }
+
+    fn local_normal_at(&self, point: &Point) -> Vector {
+        Tuple::new_vector(point.x, point.y, point.z)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(Tuple::new_point(-1.0, -1.0, -1.0), Tuple::new_point(1.0, 1.0, 1.0))
+    }
+}
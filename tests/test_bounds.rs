@@ -0,0 +1,250 @@
+use std::f64::consts::PI;
+
+use book_renderer::{
+    bounds::BoundingBox, sphere::Sphere, plane::Plane, cube::Cube, cylinder::Cylinder, cone::Cone,
+    triangle::Triangle, smooth_triangle::SmoothTriangle, intersectable::Intersectable, ray::Ray,
+    matrix4::Matrix4, tuple::{Tuple, Point}, util::Float,
+};
+
+fn point(x: Float, y: Float, z: Float) -> Point {
+    Tuple::new_point(x, y, z)
+}
+
+// `Tuple`'s `==` allows for rounding, which doesn't work out for infinities
+fn xyz(p: &Point) -> (Float, Float, Float) {
+    (p.x, p.y, p.z)
+}
+
+fn bounding_box(min: (Float, Float, Float), max: (Float, Float, Float)) -> BoundingBox {
+    BoundingBox::new(point(min.0, min.1, min.2), point(max.0, max.1, max.2))
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_empty_box() {
+    let b = BoundingBox::empty();
+    assert_eq!(xyz(&b.min), (Float::INFINITY, Float::INFINITY, Float::INFINITY));
+    assert_eq!(xyz(&b.max), (Float::NEG_INFINITY, Float::NEG_INFINITY, Float::NEG_INFINITY));
+    assert!(b.is_empty());
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_add_points_to_empty_box() {
+    let mut b = BoundingBox::empty();
+    b.add_point(&point(-5.0, 2.0, 0.0));
+    b.add_point(&point(7.0, 0.0, -3.0));
+    assert_eq!(b, bounding_box((-5.0, 0.0, -3.0), (7.0, 2.0, 0.0)));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_sphere_bounds() {
+    assert_eq!(Sphere::new_unit().bounds(), bounding_box((-1.0, -1.0, -1.0), (1.0, 1.0, 1.0)));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_plane_bounds() {
+    let b = Plane::new().bounds();
+    assert_eq!(xyz(&b.min), (Float::NEG_INFINITY, 0.0, Float::NEG_INFINITY));
+    assert_eq!(xyz(&b.max), (Float::INFINITY, 0.0, Float::INFINITY));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_cube_bounds() {
+    assert_eq!(Cube::new().bounds(), bounding_box((-1.0, -1.0, -1.0), (1.0, 1.0, 1.0)));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_cylinder_bounds() {
+    let b = Cylinder::new().bounds();
+    assert_eq!(xyz(&b.min), (-1.0, Float::NEG_INFINITY, -1.0));
+    assert_eq!(xyz(&b.max), (1.0, Float::INFINITY, 1.0));
+    assert_eq!(Cylinder::new_truncated(-5.0, 3.0, false).bounds(), bounding_box((-1.0, -5.0, -1.0), (1.0, 3.0, 1.0)));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_cone_bounds() {
+    let b = Cone::new().bounds();
+    assert_eq!(xyz(&b.min), (Float::NEG_INFINITY, Float::NEG_INFINITY, Float::NEG_INFINITY));
+    assert_eq!(xyz(&b.max), (Float::INFINITY, Float::INFINITY, Float::INFINITY));
+    assert_eq!(Cone::new_truncated(-5.0, 3.0, false).bounds(), bounding_box((-5.0, -5.0, -5.0), (5.0, 3.0, 5.0)));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_triangle_bounds() {
+    let t = Triangle::new(point(-3.0, 7.0, 2.0), point(6.0, 2.0, -4.0), point(2.0, -1.0, -1.0));
+    assert_eq!(t.bounds(), bounding_box((-3.0, -1.0, -4.0), (6.0, 7.0, 2.0)));
+    let n = Tuple::new_vector(0.0, 1.0, 0.0);
+    let s = SmoothTriangle::new(point(-3.0, 7.0, 2.0), point(6.0, 2.0, -4.0), point(2.0, -1.0, -1.0), n, n, n);
+    assert_eq!(s.bounds(), t.bounds());
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_merge_boxes() {
+    let mut b1 = bounding_box((-5.0, -2.0, 0.0), (7.0, 4.0, 4.0));
+    let b2 = bounding_box((8.0, -7.0, -2.0), (14.0, 2.0, 8.0));
+    b1.merge(&b2);
+    assert_eq!(b1, bounding_box((-5.0, -7.0, -2.0), (14.0, 4.0, 8.0)));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_box_contains_point() {
+    let b = bounding_box((5.0, -2.0, 0.0), (11.0, 4.0, 7.0));
+    let cases = [
+        (point(5.0, -2.0, 0.0), true),
+        (point(11.0, 4.0, 7.0), true),
+        (point(8.0, 1.0, 3.0), true),
+        (point(3.0, 0.0, 3.0), false),
+        (point(8.0, -4.0, 3.0), false),
+        (point(8.0, 1.0, -1.0), false),
+        (point(13.0, 1.0, 3.0), false),
+        (point(8.0, 5.0, 3.0), false),
+        (point(8.0, 1.0, 8.0), false),
+    ];
+    for (p, expected) in cases {
+        assert_eq!(b.contains_point(&p), expected, "{:?}", p);
+    }
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_box_contains_box() {
+    let b = bounding_box((5.0, -2.0, 0.0), (11.0, 4.0, 7.0));
+    let cases = [
+        (bounding_box((5.0, -2.0, 0.0), (11.0, 4.0, 7.0)), true),
+        (bounding_box((6.0, -1.0, 1.0), (10.0, 3.0, 6.0)), true),
+        (bounding_box((4.0, -3.0, -1.0), (10.0, 3.0, 6.0)), false),
+        (bounding_box((6.0, -1.0, 1.0), (12.0, 5.0, 8.0)), false),
+    ];
+    for (other, expected) in cases {
+        assert_eq!(b.contains_box(&other), expected, "{:?}", other);
+    }
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_transform_box() {
+    let b = bounding_box((-1.0, -1.0, -1.0), (1.0, 1.0, 1.0));
+    let m = Matrix4::rotation_x(PI / 4.0) * Matrix4::rotation_y(PI / 4.0);
+    let b2 = b.transform(&m);
+    assert_eq!(b2.min, point(-1.41421, -1.70711, -1.70711));
+    assert_eq!(b2.max, point(1.41421, 1.70711, 1.70711));
+}
+
+#[test]
+fn test_transform_infinite_box() {
+    // a plane moved up keeps its infinite sides and doesn't pick up any NaN
+    let b = Plane::new().bounds().transform(&Matrix4::translation(0.0, 2.0, 0.0));
+    assert_eq!(xyz(&b.min), (Float::NEG_INFINITY, 2.0, Float::NEG_INFINITY));
+    assert_eq!(xyz(&b.max), (Float::INFINITY, 2.0, Float::INFINITY));
+    assert!(BoundingBox::empty().transform(&Matrix4::translation(1.0, 0.0, 0.0)).is_empty());
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_ray_intersects_cubic_box() {
+    let b = bounding_box((-1.0, -1.0, -1.0), (1.0, 1.0, 1.0));
+    let cases = [
+        (point(5.0, 0.5, 0.0), Tuple::new_vector(-1.0, 0.0, 0.0), true),
+        (point(-5.0, 0.5, 0.0), Tuple::new_vector(1.0, 0.0, 0.0), true),
+        (point(0.5, 5.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0), true),
+        (point(0.5, -5.0, 0.0), Tuple::new_vector(0.0, 1.0, 0.0), true),
+        (point(0.5, 0.0, 5.0), Tuple::new_vector(0.0, 0.0, -1.0), true),
+        (point(0.5, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0), true),
+        (point(0.0, 0.5, 0.0), Tuple::new_vector(0.0, 0.0, 1.0), true),
+        (point(-2.0, 0.0, 0.0), Tuple::new_vector(2.0, 4.0, 6.0), false),
+        (point(0.0, -2.0, 0.0), Tuple::new_vector(6.0, 2.0, 4.0), false),
+        (point(0.0, 0.0, -2.0), Tuple::new_vector(4.0, 6.0, 2.0), false),
+        (point(2.0, 0.0, 2.0), Tuple::new_vector(0.0, 0.0, -1.0), false),
+        (point(0.0, 2.0, 2.0), Tuple::new_vector(0.0, -1.0, 0.0), false),
+        (point(2.0, 2.0, 0.0), Tuple::new_vector(-1.0, 0.0, 0.0), false),
+    ];
+    for (origin, direction, expected) in cases {
+        let r = Ray::new(origin, direction.normalize().unwrap());
+        assert_eq!(b.intersects(&r), expected, "{:?} {:?}", origin, direction);
+    }
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_ray_intersects_noncubic_box() {
+    let b = bounding_box((5.0, -2.0, 0.0), (11.0, 4.0, 7.0));
+    let cases = [
+        (point(15.0, 1.0, 2.0), Tuple::new_vector(-1.0, 0.0, 0.0), true),
+        (point(-5.0, -1.0, 4.0), Tuple::new_vector(1.0, 0.0, 0.0), true),
+        (point(7.0, 6.0, 5.0), Tuple::new_vector(0.0, -1.0, 0.0), true),
+        (point(9.0, -5.0, 6.0), Tuple::new_vector(0.0, 1.0, 0.0), true),
+        (point(8.0, 2.0, 12.0), Tuple::new_vector(0.0, 0.0, -1.0), true),
+        (point(6.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0), true),
+        (point(8.0, 1.0, 3.5), Tuple::new_vector(0.0, 0.0, 1.0), true),
+        (point(9.0, -1.0, -8.0), Tuple::new_vector(2.0, 4.0, 6.0), false),
+        (point(8.0, 3.0, -4.0), Tuple::new_vector(6.0, 2.0, 4.0), false),
+        (point(9.0, -1.0, -2.0), Tuple::new_vector(4.0, 6.0, 2.0), false),
+        (point(4.0, 0.0, 9.0), Tuple::new_vector(0.0, 0.0, -1.0), false),
+        (point(8.0, 6.0, -1.0), Tuple::new_vector(0.0, -1.0, 0.0), false),
+        (point(12.0, 5.0, 4.0), Tuple::new_vector(-1.0, 0.0, 0.0), false),
+    ];
+    for (origin, direction, expected) in cases {
+        let r = Ray::new(origin, direction.normalize().unwrap());
+        assert_eq!(b.intersects(&r), expected, "{:?} {:?}", origin, direction);
+    }
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_split_perfect_cube() {
+    let (left, right) = bounding_box((-1.0, -4.0, -5.0), (9.0, 6.0, 5.0)).split();
+    assert_eq!(left, bounding_box((-1.0, -4.0, -5.0), (4.0, 6.0, 5.0)));
+    assert_eq!(right, bounding_box((4.0, -4.0, -5.0), (9.0, 6.0, 5.0)));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_split_wide_box() {
+    let (left, right) = bounding_box((-1.0, -2.0, -3.0), (9.0, 5.5, 3.0)).split();
+    assert_eq!(left, bounding_box((-1.0, -2.0, -3.0), (4.0, 5.5, 3.0)));
+    assert_eq!(right, bounding_box((4.0, -2.0, -3.0), (9.0, 5.5, 3.0)));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_split_tall_box() {
+    let (left, right) = bounding_box((-1.0, -2.0, -3.0), (5.0, 8.0, 3.0)).split();
+    assert_eq!(left, bounding_box((-1.0, -2.0, -3.0), (5.0, 3.0, 3.0)));
+    assert_eq!(right, bounding_box((-1.0, 3.0, -3.0), (5.0, 8.0, 3.0)));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_split_deep_box() {
+    let (left, right) = bounding_box((-1.0, -2.0, -3.0), (5.0, 3.0, 7.0)).split();
+    assert_eq!(left, bounding_box((-1.0, -2.0, -3.0), (5.0, 3.0, 2.0)));
+    assert_eq!(right, bounding_box((-1.0, -2.0, 2.0), (5.0, 3.0, 7.0)));
+}
+
+#[test]
+fn test_entry_exit() {
+    let b = bounding_box((-1.0, -1.0, -1.0), (1.0, 1.0, 1.0));
+    let r = Ray::new(Tuple::new_point(0.5, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_eq!(b.entry_exit(&r), Some((4.0, 6.0)));
+    let r = Ray::new(Tuple::new_point(0.5, 0.0, 0.0), Tuple::new_vector(0.0, 0.0, 2.0));
+    assert_eq!(b.entry_exit(&r), Some((-0.5, 0.5)));
+    let r = Ray::new(Tuple::new_point(2.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_eq!(b.entry_exit(&r), None);
+    assert_eq!(BoundingBox::empty().entry_exit(&r), None);
+}
+
+#[test]
+fn test_expand() {
+    let b = bounding_box((-1.0, 0.0, 2.0), (1.0, 3.0, 4.0)).expand(0.5);
+    assert_eq!(b, bounding_box((-1.5, -0.5, 1.5), (1.5, 3.5, 4.5)));
+    assert!(BoundingBox::empty().expand(1.0).is_empty());
+}
@@ -0,0 +1,107 @@
+use std::{error::Error, f64::consts::PI};
+
+use book_renderer::{camera::Camera, matrix4::Matrix4, tuple::Tuple, util::{feq, Float}, world::World, color::Color};
+
+// pg 101
+#[test]
+fn test_book_construct_camera() {
+    let c = Camera::new(160, 120, PI/2.0);
+    assert_eq!(c.hsize(), 160);
+    assert_eq!(c.vsize(), 120);
+    assert_eq!(c.field_of_view(), PI/2.0);
+    assert_eq!(c.transformation(), &Matrix4::identity());
+}
+
+// pg 101
+#[test]
+fn test_book_pixel_size_horizontal() {
+    let c = Camera::new(200, 125, PI/2.0);
+    assert!(feq(&c.pixel_size(), &0.01));
+}
+
+// pg 101
+#[test]
+fn test_book_pixel_size_vertical() {
+    let c = Camera::new(125, 200, PI/2.0);
+    assert!(feq(&c.pixel_size(), &0.01));
+}
+
+// pg 103
+#[test]
+fn test_book_ray_through_center() {
+    let c = Camera::new(201, 101, PI/2.0);
+    let r = c.ray_for_pixel(100, 50);
+    assert_eq!(r.origin, Tuple::origin_point());
+    assert_eq!(r.direction, Tuple::new_vector(0.0, 0.0, -1.0));
+}
+
+// pg 103
+#[test]
+fn test_book_ray_through_corner() {
+    let c = Camera::new(201, 101, PI/2.0);
+    let r = c.ray_for_pixel(0, 0);
+    assert_eq!(r.origin, Tuple::origin_point());
+    assert_eq!(r.direction, Tuple::new_vector(0.66519, 0.33259, -0.66851));
+}
+
+// pg 103
+#[test]
+fn test_book_ray_transformed_camera() {
+    let mut c = Camera::new(201, 101, PI/2.0);
+    c.set_transform(Matrix4::rotation_y(PI/4.0) * Matrix4::translation(0.0, -2.0, 5.0)).unwrap();
+    let r = c.ray_for_pixel(100, 50);
+    assert_eq!(r.origin, Tuple::new_point(0.0, 2.0, -5.0));
+    assert_eq!(r.direction, Tuple::new_vector((2.0 as Float).sqrt()/2.0, 0.0, -(2.0 as Float).sqrt()/2.0));
+}
+
+// pg 104
+#[test]
+fn test_book_render_world() -> Result<(), Box<dyn Error>> {
+    let w = World::default();
+    let mut c = Camera::new(11, 11, PI/2.0);
+    let from = Tuple::new_point(0.0, 0.0, -5.0);
+    let to = Tuple::origin_point();
+    let up = Tuple::new_vector(0.0, 1.0, 0.0);
+    c.set_transform(Matrix4::view_transform(&from, &to, &up)?)?;
+    let image = c.render(&w);
+    assert_eq!(image[(5,5)], Color::new(0.38066, 0.47583, 0.2855));
+    Ok(())
+}
+
+#[test]
+fn test_camera_rejects_singular_transform() {
+    let mut c = Camera::new(11, 11, PI/2.0);
+    assert!(c.set_transform(Matrix4::scaling(1.0, 0.0, 1.0)).is_err());
+    assert_eq!(c.transformation(), &Matrix4::identity());
+}
+
+#[test]
+fn test_render_threads_match_single_thread() -> Result<(), Box<dyn Error>> {
+    let w = World::default();
+    let mut c = Camera::new(21, 15, PI/2.0);
+    c.set_transform(Matrix4::view_transform(&Tuple::new_point(0.0, 0.0, -5.0), &Tuple::origin_point(), &Tuple::new_vector(0.0, 1.0, 0.0))?)?;
+    c.set_threads(1);
+    let single = c.render(&w);
+    c.set_threads(4);
+    let multi = c.render(&w);
+    for y in 0..15 {
+        for x in 0..21 {
+            assert_eq!(single[(x,y)], multi[(x,y)]);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_camera_threads_at_least_one() {
+    let mut c = Camera::new(11, 11, PI/2.0);
+    assert!(c.threads() >= 1);
+    c.set_threads(0);
+    assert_eq!(c.threads(), 1);
+}
+
+#[test]
+fn test_world_is_shareable_between_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<World>();
+}
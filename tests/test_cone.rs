@@ -0,0 +1,87 @@
+use book_renderer::{cone::Cone, ray::Ray, tuple::Tuple, util::{feq, Float}, intersectable::Intersectable};
+
+fn ray(origin: Tuple, direction: Tuple) -> Ray {
+    Ray::new(origin, direction.normalize().unwrap())
+}
+
+// pg 189
+#[test]
+fn test_book_ray_hits_cone() {
+    let shape = Cone::new();
+    let cases = [
+        (Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0), 5.0, 5.0),
+        (Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(1.0, 1.0, 1.0), 8.66025, 8.66025),
+        (Tuple::new_point(1.0, 1.0, -5.0), Tuple::new_vector(-0.5, -1.0, 1.0), 4.55006, 49.44994),
+    ];
+    for (origin, direction, t0, t1) in cases {
+        let xs = shape.local_intersect(&ray(origin, direction));
+        assert_eq!(xs.len(), 2);
+        assert!(feq(&xs[0].t, &t0), "{} != {}", xs[0].t, t0);
+        assert!(feq(&xs[1].t, &t1), "{} != {}", xs[1].t, t1);
+    }
+}
+
+// pg 190
+#[test]
+fn test_book_ray_parallel_to_one_half() {
+    let shape = Cone::new();
+    let xs = shape.local_intersect(&ray(Tuple::new_point(0.0, 0.0, -1.0), Tuple::new_vector(0.0, 1.0, 1.0)));
+    assert_eq!(xs.len(), 1);
+    assert!(feq(&xs[0].t, &0.35355));
+}
+
+// pg 190
+#[test]
+fn test_book_capped_cone() {
+    let shape = Cone::new_truncated(-0.5, 0.5, true);
+    let cases = [
+        (Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 1.0, 0.0), 0),
+        (Tuple::new_point(0.0, 0.0, -0.25), Tuple::new_vector(0.0, 1.0, 1.0), 2),
+        (Tuple::new_point(0.0, 0.0, -0.25), Tuple::new_vector(0.0, 1.0, 0.0), 4),
+    ];
+    for (origin, direction, count) in cases {
+        assert_eq!(shape.local_intersect(&ray(origin, direction)).len(), count);
+    }
+}
+
+// pg 191
+#[test]
+fn test_book_cone_normal() {
+    let shape = Cone::new();
+    let cases = [
+        (Tuple::new_point(0.0, 0.0, 0.0), Tuple::new_vector(0.0, 0.0, 0.0)),
+        (Tuple::new_point(1.0, 1.0, 1.0), Tuple::new_vector(1.0, -(2.0 as Float).sqrt(), 1.0)),
+        (Tuple::new_point(-1.0, -1.0, 0.0), Tuple::new_vector(-1.0, 1.0, 0.0)),
+    ];
+    for (point, normal) in cases {
+        assert_eq!(shape.local_normal_at(&point), normal);
+    }
+}
+
+#[test]
+fn test_cone_defaults() {
+    let shape = Cone::new();
+    assert_eq!(shape.minimum, Float::NEG_INFINITY);
+    assert_eq!(shape.maximum, Float::INFINITY);
+    assert!(!shape.closed);
+}
+
+#[test]
+fn test_cone_cap_normals() {
+    let shape = Cone::new_truncated(-1.0, 2.0, true);
+    assert_eq!(shape.local_normal_at(&Tuple::new_point(0.5, 2.0, 0.0)), Tuple::new_vector(0.0, 1.0, 0.0));
+    assert_eq!(shape.local_normal_at(&Tuple::new_point(0.0, -1.0, 0.5)), Tuple::new_vector(0.0, -1.0, 0.0));
+}
+
+#[test]
+fn test_single_napped_cone() {
+    // cut at the tip, so only the upper half is left
+    let shape = Cone::new_truncated(0.0, 1.0, true);
+    // in through the top cap and out through the wall, never reaching the lower half
+    let down = ray(Tuple::new_point(0.5, 5.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0));
+    let mut ts: Vec<Float> = shape.local_intersect(&down).iter().map(|i| i.t).collect();
+    ts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(ts, vec![4.0, 4.5]);
+    let across_below = ray(Tuple::new_point(-5.0, -0.5, 0.0), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert!(shape.local_intersect(&across_below).is_empty());
+}
@@ -0,0 +1,186 @@
+use book_renderer::{
+    csg::{Csg, CsgOperation}, group::Group, sphere::Sphere, cube::Cube, intersectable::Intersectable,
+    intersection::Intersection, bounds::BoundingBox, ray::Ray, matrix4::Matrix4, tuple::Tuple,
+};
+
+mod common;
+use common::translated_sphere;
+
+fn same(a: &dyn Intersectable, b: &dyn Intersectable) -> bool {
+    std::ptr::addr_eq(a as *const dyn Intersectable, b as *const dyn Intersectable)
+}
+
+// pg 230
+#[test]
+fn test_book_create_csg() {
+    let c = Csg::new(CsgOperation::Union, Box::new(Sphere::new_unit()), Box::new(Cube::new()));
+    assert_eq!(c.operation(), CsgOperation::Union);
+    assert!(c.left().as_any().is::<Sphere>());
+    assert!(c.right().as_any().is::<Cube>());
+    assert_eq!(c.left().shape().parent_inverse(), c.shape().world_inverse());
+    assert_eq!(c.right().shape().parent_inverse(), c.shape().world_inverse());
+}
+
+// pg 234
+#[test]
+fn test_book_union_rules() {
+    let cases = [
+        (true, true, true, false),
+        (true, true, false, true),
+        (true, false, true, false),
+        (true, false, false, true),
+        (false, true, true, false),
+        (false, true, false, false),
+        (false, false, true, true),
+        (false, false, false, true),
+    ];
+    for (lhit, inl, inr, expected) in cases {
+        assert_eq!(CsgOperation::Union.intersection_allowed(lhit, inl, inr), expected, "{} {} {}", lhit, inl, inr);
+    }
+}
+
+// pg 235
+#[test]
+fn test_book_intersection_rules() {
+    let cases = [
+        (true, true, true, true),
+        (true, true, false, false),
+        (true, false, true, true),
+        (true, false, false, false),
+        (false, true, true, true),
+        (false, true, false, true),
+        (false, false, true, false),
+        (false, false, false, false),
+    ];
+    for (lhit, inl, inr, expected) in cases {
+        assert_eq!(CsgOperation::Intersection.intersection_allowed(lhit, inl, inr), expected, "{} {} {}", lhit, inl, inr);
+    }
+}
+
+// pg 235
+#[test]
+fn test_book_difference_rules() {
+    let cases = [
+        (true, true, true, false),
+        (true, true, false, true),
+        (true, false, true, false),
+        (true, false, false, true),
+        (false, true, true, true),
+        (false, true, false, true),
+        (false, false, true, false),
+        (false, false, false, false),
+    ];
+    for (lhit, inl, inr, expected) in cases {
+        assert_eq!(CsgOperation::Difference.intersection_allowed(lhit, inl, inr), expected, "{} {} {}", lhit, inl, inr);
+    }
+}
+
+// pg 236
+#[test]
+fn test_book_filter_intersections() {
+    let cases = [
+        (CsgOperation::Union, 0, 3),
+        (CsgOperation::Intersection, 1, 2),
+        (CsgOperation::Difference, 0, 1),
+    ];
+    for (operation, x0, x1) in cases {
+        let c = Csg::new(operation, Box::new(Sphere::new_unit()), Box::new(Cube::new()));
+        let xs = vec![
+            Intersection::new(1.0, c.left()),
+            Intersection::new(2.0, c.right()),
+            Intersection::new(3.0, c.left()),
+            Intersection::new(4.0, c.right()),
+        ];
+        let result = c.filter_intersections(xs.clone());
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0], xs[x0]);
+        assert_eq!(result[1], xs[x1]);
+    }
+}
+
+// pg 236
+#[test]
+fn test_book_ray_misses_csg() {
+    let c = Csg::new(CsgOperation::Union, Box::new(Sphere::new_unit()), Box::new(Cube::new()));
+    let r = Ray::new(Tuple::new_point(0.0, 2.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert!(c.local_intersect(&r).is_empty());
+}
+
+// pg 236
+#[test]
+fn test_book_ray_hits_csg() {
+    let c = Csg::new(CsgOperation::Union, Box::new(Sphere::new_unit()), translated_sphere(0.0, 0.0, 0.5));
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let xs = c.local_intersect(&r);
+    assert_eq!(xs.len(), 2);
+    assert_eq!(xs[0].t, 4.0);
+    assert!(same(xs[0].object, c.left()));
+    assert_eq!(xs[1].t, 6.5);
+    assert!(same(xs[1].object, c.right()));
+}
+
+#[test]
+fn test_includes() {
+    let mut g = Group::new();
+    g.add_child(Box::new(Sphere::new_unit()));
+    let c = Csg::new(CsgOperation::Difference, Box::new(g), Box::new(Cube::new()));
+    let g = c.left().as_any().downcast_ref::<Group>().unwrap();
+    let s = g.children()[0].as_ref();
+    assert!(s.includes(s));
+    assert!(g.includes(s));
+    assert!(c.includes(s));
+    assert!(c.includes(c.right()));
+    assert!(!c.right().includes(s));
+    assert!(!s.includes(&Sphere::new_unit()));
+}
+
+#[test]
+fn test_difference_through_group() {
+    // a group on the left still counts as the left side, so a cube with a sphere-shaped bite
+    // taken out of its front face is hit at the back of the bite
+    let mut g = Group::new();
+    g.add_child(Box::new(Cube::new()));
+    let c = Csg::new(CsgOperation::Difference, Box::new(g), translated_sphere(0.0, 0.0, -1.0));
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let xs = c.intersections(&r);
+    assert_eq!(xs.iter().map(|x| x.t).collect::<Vec<f64>>(), vec![5.0, 6.0]);
+    assert!(xs[0].object.as_any().is::<Sphere>());
+    // the surface of the bite faces out of the cube, into the hole
+    assert_eq!(xs[0].object.surface_normal(&Tuple::origin_point()), Tuple::new_vector(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_transformed_csg_moves_both_sides() {
+    let mut c = Csg::new(CsgOperation::Intersection, Box::new(Sphere::new_unit()), translated_sphere(0.0, 0.0, 1.0));
+    c.set_transform(Matrix4::translation(0.0, 0.0, 10.0)).unwrap();
+    let r = Ray::new(Tuple::origin_point(), Tuple::new_vector(0.0, 0.0, 1.0));
+    let xs = c.intersections(&r);
+    // the lens where the two spheres overlap runs from z = 10 to z = 11
+    assert_eq!(xs.iter().map(|x| x.t).collect::<Vec<f64>>(), vec![10.0, 11.0]);
+    assert_eq!(xs[0].object.surface_normal(&Tuple::new_point(0.0, 0.0, 10.0)), Tuple::new_vector(0.0, 0.0, -1.0));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_csg_bounds() {
+    let c = Csg::new(CsgOperation::Difference, Box::new(Sphere::new_unit()), translated_sphere(2.0, 3.0, 4.0));
+    assert_eq!(c.bounds(), BoundingBox::new(Tuple::new_point(-1.0, -1.0, -1.0), Tuple::new_point(3.0, 4.0, 5.0)));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_divide_csg_divides_children() {
+    let mut left = Group::new();
+    left.add_child(translated_sphere(-1.5, 0.0, 0.0));
+    left.add_child(translated_sphere(1.5, 0.0, 0.0));
+    let mut right = Group::new();
+    right.add_child(translated_sphere(0.0, 0.0, -1.5));
+    right.add_child(translated_sphere(0.0, 0.0, 1.5));
+    let mut c = Csg::new(CsgOperation::Difference, Box::new(left), Box::new(right));
+    c.divide(1);
+    for side in [c.left(), c.right()] {
+        let g = side.as_any().downcast_ref::<Group>().unwrap();
+        assert_eq!(g.len(), 2);
+        assert!(g.children().iter().all(|child| child.as_any().is::<Group>()));
+    }
+}
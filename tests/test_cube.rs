@@ -0,0 +1,69 @@
+use book_renderer::{cube::Cube, matrix4::Matrix4, ray::Ray, tuple::Tuple, intersectable::Intersectable};
+
+// pg 168
+#[test]
+fn test_book_ray_intersects_cube() {
+    let c = Cube::new();
+    let cases = [
+        (Tuple::new_point(5.0, 0.5, 0.0), Tuple::new_vector(-1.0, 0.0, 0.0), 4.0, 6.0),
+        (Tuple::new_point(-5.0, 0.5, 0.0), Tuple::new_vector(1.0, 0.0, 0.0), 4.0, 6.0),
+        (Tuple::new_point(0.5, 5.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0), 4.0, 6.0),
+        (Tuple::new_point(0.5, -5.0, 0.0), Tuple::new_vector(0.0, 1.0, 0.0), 4.0, 6.0),
+        (Tuple::new_point(0.5, 0.0, 5.0), Tuple::new_vector(0.0, 0.0, -1.0), 4.0, 6.0),
+        (Tuple::new_point(0.5, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0), 4.0, 6.0),
+        (Tuple::new_point(0.0, 0.5, 0.0), Tuple::new_vector(0.0, 0.0, 1.0), -1.0, 1.0),
+    ];
+    for (origin, direction, t1, t2) in cases {
+        let xs = c.intersections(&Ray::new(origin, direction));
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, t1);
+        assert_eq!(xs[1].t, t2);
+    }
+}
+
+// pg 172
+#[test]
+fn test_book_ray_misses_cube() {
+    let c = Cube::new();
+    let cases = [
+        (Tuple::new_point(-2.0, 0.0, 0.0), Tuple::new_vector(0.2673, 0.5345, 0.8018)),
+        (Tuple::new_point(0.0, -2.0, 0.0), Tuple::new_vector(0.8018, 0.2673, 0.5345)),
+        (Tuple::new_point(0.0, 0.0, -2.0), Tuple::new_vector(0.5345, 0.8018, 0.2673)),
+        (Tuple::new_point(2.0, 0.0, 2.0), Tuple::new_vector(0.0, 0.0, -1.0)),
+        (Tuple::new_point(0.0, 2.0, 2.0), Tuple::new_vector(0.0, -1.0, 0.0)),
+        (Tuple::new_point(2.0, 2.0, 0.0), Tuple::new_vector(-1.0, 0.0, 0.0)),
+    ];
+    for (origin, direction) in cases {
+        assert!(c.intersections(&Ray::new(origin, direction)).is_empty());
+    }
+}
+
+// pg 173
+#[test]
+fn test_book_cube_normal() {
+    let c = Cube::new();
+    let cases = [
+        (Tuple::new_point(1.0, 0.5, -0.8), Tuple::new_vector(1.0, 0.0, 0.0)),
+        (Tuple::new_point(-1.0, -0.2, 0.9), Tuple::new_vector(-1.0, 0.0, 0.0)),
+        (Tuple::new_point(-0.4, 1.0, -0.1), Tuple::new_vector(0.0, 1.0, 0.0)),
+        (Tuple::new_point(0.3, -1.0, -0.7), Tuple::new_vector(0.0, -1.0, 0.0)),
+        (Tuple::new_point(-0.6, 0.3, 1.0), Tuple::new_vector(0.0, 0.0, 1.0)),
+        (Tuple::new_point(0.4, 0.4, -1.0), Tuple::new_vector(0.0, 0.0, -1.0)),
+        (Tuple::new_point(1.0, 1.0, 1.0), Tuple::new_vector(1.0, 0.0, 0.0)),
+        (Tuple::new_point(-1.0, -1.0, -1.0), Tuple::new_vector(-1.0, 0.0, 0.0)),
+    ];
+    for (point, normal) in cases {
+        assert_eq!(c.local_normal_at(&point), normal);
+    }
+}
+
+#[test]
+fn test_transformed_cube() {
+    let mut c = Cube::new();
+    c.set_transform(Matrix4::identity().scale(2.0, 0.5, 1.0).translate(0.0, 3.0, 0.0)).unwrap();
+    let xs = c.intersections(&Ray::new(Tuple::new_point(0.0, 10.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0)));
+    assert_eq!(xs.len(), 2);
+    assert_eq!(xs[0].t, 6.5);
+    assert_eq!(xs[1].t, 7.5);
+    assert_eq!(c.surface_normal(&Tuple::new_point(2.0, 3.0, 0.0)), Tuple::new_vector(1.0, 0.0, 0.0));
+}
@@ -0,0 +1,124 @@
+use book_renderer::{cylinder::Cylinder, matrix4::Matrix4, ray::Ray, tuple::Tuple, util::{feq, Float}, intersectable::Intersectable};
+
+fn ray(origin: Tuple, direction: Tuple) -> Ray {
+    Ray::new(origin, direction.normalize().unwrap())
+}
+
+// pg 178
+#[test]
+fn test_book_ray_misses_cylinder() {
+    let cyl = Cylinder::new();
+    let cases = [
+        (Tuple::new_point(1.0, 0.0, 0.0), Tuple::new_vector(0.0, 1.0, 0.0)),
+        (Tuple::new_point(0.0, 0.0, 0.0), Tuple::new_vector(0.0, 1.0, 0.0)),
+        (Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(1.0, 1.0, 1.0)),
+    ];
+    for (origin, direction) in cases {
+        assert!(cyl.local_intersect(&ray(origin, direction)).is_empty());
+    }
+}
+
+// pg 180
+#[test]
+fn test_book_ray_hits_cylinder() {
+    let cyl = Cylinder::new();
+    let cases = [
+        (Tuple::new_point(1.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0), 5.0, 5.0),
+        (Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0), 4.0, 6.0),
+        (Tuple::new_point(0.5, 0.0, -5.0), Tuple::new_vector(0.1, 1.0, 1.0), 6.80798, 7.08872),
+    ];
+    for (origin, direction, t0, t1) in cases {
+        let xs = cyl.local_intersect(&ray(origin, direction));
+        assert_eq!(xs.len(), 2);
+        assert!(feq(&xs[0].t, &t0));
+        assert!(feq(&xs[1].t, &t1));
+    }
+}
+
+// pg 181
+#[test]
+fn test_book_cylinder_normal() {
+    let cyl = Cylinder::new();
+    let cases = [
+        (Tuple::new_point(1.0, 0.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0)),
+        (Tuple::new_point(0.0, 5.0, -1.0), Tuple::new_vector(0.0, 0.0, -1.0)),
+        (Tuple::new_point(0.0, -2.0, 1.0), Tuple::new_vector(0.0, 0.0, 1.0)),
+        (Tuple::new_point(-1.0, 1.0, 0.0), Tuple::new_vector(-1.0, 0.0, 0.0)),
+    ];
+    for (point, normal) in cases {
+        assert_eq!(cyl.local_normal_at(&point), normal);
+    }
+}
+
+// pg 182 and pg 185
+#[test]
+fn test_book_cylinder_defaults() {
+    let cyl = Cylinder::new();
+    assert_eq!(cyl.minimum, Float::NEG_INFINITY);
+    assert_eq!(cyl.maximum, Float::INFINITY);
+    assert!(!cyl.closed);
+}
+
+// pg 183
+#[test]
+fn test_book_truncated_cylinder() {
+    let cyl = Cylinder::new_truncated(1.0, 2.0, false);
+    let cases = [
+        (Tuple::new_point(0.0, 1.5, 0.0), Tuple::new_vector(0.1, 1.0, 0.0), 0),
+        (Tuple::new_point(0.0, 3.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0), 0),
+        (Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0), 0),
+        (Tuple::new_point(0.0, 2.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0), 0),
+        (Tuple::new_point(0.0, 1.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0), 0),
+        (Tuple::new_point(0.0, 1.5, -2.0), Tuple::new_vector(0.0, 0.0, 1.0), 2),
+    ];
+    for (origin, direction, count) in cases {
+        assert_eq!(cyl.local_intersect(&ray(origin, direction)).len(), count);
+    }
+}
+
+// pg 185
+#[test]
+fn test_book_capped_cylinder() {
+    let cyl = Cylinder::new_truncated(1.0, 2.0, true);
+    let cases = [
+        (Tuple::new_point(0.0, 3.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0)),
+        (Tuple::new_point(0.0, 3.0, -2.0), Tuple::new_vector(0.0, -1.0, 2.0)),
+        (Tuple::new_point(0.0, 4.0, -2.0), Tuple::new_vector(0.0, -1.0, 1.0)),
+        (Tuple::new_point(0.0, 0.0, -2.0), Tuple::new_vector(0.0, 1.0, 2.0)),
+        (Tuple::new_point(0.0, -1.0, -2.0), Tuple::new_vector(0.0, 1.0, 1.0)),
+    ];
+    for (origin, direction) in cases {
+        assert_eq!(cyl.local_intersect(&ray(origin, direction)).len(), 2);
+    }
+}
+
+// pg 187
+#[test]
+fn test_book_cylinder_cap_normals() {
+    let cyl = Cylinder::new_truncated(1.0, 2.0, true);
+    let cases = [
+        (Tuple::new_point(0.0, 1.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0)),
+        (Tuple::new_point(0.5, 1.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0)),
+        (Tuple::new_point(0.0, 1.0, 0.5), Tuple::new_vector(0.0, -1.0, 0.0)),
+        (Tuple::new_point(0.0, 2.0, 0.0), Tuple::new_vector(0.0, 1.0, 0.0)),
+        (Tuple::new_point(0.5, 2.0, 0.0), Tuple::new_vector(0.0, 1.0, 0.0)),
+        (Tuple::new_point(0.0, 2.0, 0.5), Tuple::new_vector(0.0, 1.0, 0.0)),
+    ];
+    for (point, normal) in cases {
+        assert_eq!(cyl.local_normal_at(&point), normal);
+    }
+}
+
+#[test]
+fn test_transformed_capped_cylinder() {
+    let mut cyl = Cylinder::new_truncated(0.0, 1.0, true);
+    cyl.set_transform(Matrix4::identity().scale(0.5, 3.0, 0.5)).unwrap();
+    let xs = cyl.intersections(&Ray::new(Tuple::new_point(0.0, 10.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0)));
+    let mut ts: Vec<Float> = xs.iter().map(|i| i.t).collect();
+    ts.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(ts.len(), 2);
+    assert!(feq(&ts[0], &7.0));
+    assert!(feq(&ts[1], &10.0));
+    assert_eq!(cyl.surface_normal(&Tuple::new_point(0.1, 3.0, 0.0)), Tuple::new_vector(0.0, 1.0, 0.0));
+    assert_eq!(cyl.surface_normal(&Tuple::new_point(0.5, 1.5, 0.0)), Tuple::new_vector(1.0, 0.0, 0.0));
+}
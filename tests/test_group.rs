@@ -0,0 +1,291 @@
+use std::f64::consts::PI;
+
+use book_renderer::{
+    group::Group, sphere::Sphere, cylinder::Cylinder, cone::Cone, intersectable::Intersectable, ray::Ray,
+    tuple::{Tuple, Vector}, matrix4::Matrix4, color::Color, pattern::StripePattern,
+    bounds::BoundingBox, util::feq_precision,
+};
+
+mod common;
+use common::{TestShape, translated_sphere};
+
+fn saved_ray(group: &Group, index: usize) -> Option<Ray> {
+    group.children()[index].as_any().downcast_ref::<TestShape>().unwrap().saved_ray()
+}
+
+fn subgroup(group: &Group, index: usize) -> &Group {
+    group.children()[index].as_any().downcast_ref::<Group>().unwrap()
+}
+
+fn is_child(object: &dyn Intersectable, group: &Group, index: usize) -> bool {
+    std::ptr::addr_eq(object as *const dyn Intersectable, group.children()[index].as_ref() as *const dyn Intersectable)
+}
+
+// The book's values here only have 4 decimal places
+fn assert_vector_near(actual: Vector, expected: Vector) {
+    assert!(feq_precision(&actual.x, &expected.x, 4) && feq_precision(&actual.y, &expected.y, 4) && feq_precision(&actual.z, &expected.z, 4),
+        "{:?} is not {:?}", actual, expected);
+}
+
+// pg 198: g1 is rotated and holds g2, which is scaled and holds a translated sphere
+fn nested_groups(g2_scaling: Matrix4) -> Group {
+    let mut g2 = Group::new();
+    g2.set_transform(g2_scaling).unwrap();
+    g2.add_child(translated_sphere(5.0, 0.0, 0.0));
+    let mut g1 = Group::new();
+    g1.set_transform(Matrix4::rotation_y(PI / 2.0)).unwrap();
+    g1.add_child(Box::new(g2));
+    g1
+}
+
+fn innermost(g1: &Group) -> &dyn Intersectable {
+    let g2 = g1.children()[0].as_any().downcast_ref::<Group>().unwrap();
+    g2.children()[0].as_ref()
+}
+
+// pg 195
+#[test]
+fn test_book_create_group() {
+    let g = Group::new();
+    assert_eq!(g.transformation(), &Matrix4::identity());
+    assert!(g.is_empty());
+}
+
+// pg 195
+#[test]
+fn test_book_shape_has_no_parent() {
+    let s = Sphere::new_unit();
+    assert_eq!(s.shape().parent_inverse(), &Matrix4::identity());
+}
+
+// pg 195
+#[test]
+fn test_book_add_child() {
+    let mut g = Group::new();
+    g.set_transform(Matrix4::translation(1.0, 0.0, 0.0)).unwrap();
+    g.add_child(Box::new(Sphere::new_unit()));
+    assert_eq!(g.len(), 1);
+    assert!(g.children()[0].as_any().is::<Sphere>());
+    assert_eq!(g.children()[0].shape().parent_inverse(), g.shape().inverse());
+}
+
+// pg 196
+#[test]
+fn test_book_intersect_empty_group() {
+    let g = Group::new();
+    let r = Ray::new(Tuple::origin_point(), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert!(g.local_intersect(&r).is_empty());
+}
+
+// pg 196
+#[test]
+fn test_book_intersect_nonempty_group() {
+    let mut g = Group::new();
+    g.add_child(Box::new(Sphere::new_unit()));
+    g.add_child(translated_sphere(0.0, 0.0, -3.0));
+    g.add_child(translated_sphere(5.0, 0.0, 0.0));
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let xs = g.local_intersect(&r);
+    assert_eq!(xs.len(), 4);
+    assert!(is_child(xs[0].object, &g, 1));
+    assert!(is_child(xs[1].object, &g, 1));
+    assert!(is_child(xs[2].object, &g, 0));
+    assert!(is_child(xs[3].object, &g, 0));
+}
+
+// pg 197
+#[test]
+fn test_book_intersect_transformed_group() {
+    let mut g = Group::new();
+    g.set_transform(Matrix4::scaling(2.0, 2.0, 2.0)).unwrap();
+    g.add_child(translated_sphere(5.0, 0.0, 0.0));
+    let r = Ray::new(Tuple::new_point(10.0, 0.0, -10.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_eq!(g.intersections(&r).len(), 2);
+}
+
+// pg 198
+#[test]
+fn test_book_world_to_object() {
+    let g1 = nested_groups(Matrix4::scaling(2.0, 2.0, 2.0));
+    let s = innermost(&g1);
+    assert_eq!(s.world_to_object(&Tuple::new_point(-2.0, 0.0, -10.0)), Tuple::new_point(0.0, 0.0, -1.0));
+}
+
+// pg 198
+#[test]
+fn test_book_normal_to_world() {
+    let g1 = nested_groups(Matrix4::scaling(1.0, 2.0, 3.0));
+    let s = innermost(&g1);
+    let r3 = 3.0_f64.sqrt() / 3.0;
+    let n = s.normal_to_world(&Tuple::new_vector(r3, r3, r3));
+    assert_vector_near(n, Tuple::new_vector(0.2857, 0.4286, -0.8571));
+}
+
+// pg 199
+#[test]
+fn test_book_normal_on_child() {
+    let g1 = nested_groups(Matrix4::scaling(1.0, 2.0, 3.0));
+    let s = innermost(&g1);
+    let n = s.surface_normal(&Tuple::new_point(1.7321, 1.1547, -5.5774));
+    assert_vector_near(n, Tuple::new_vector(0.2857, 0.4286, -0.8571));
+}
+
+#[test]
+fn test_group_moved_after_children_added() {
+    let mut g = Group::new();
+    g.add_child(Box::new(Sphere::new_unit()));
+    g.set_transform(Matrix4::translation(0.0, 0.0, 10.0)).unwrap();
+    let r = Ray::new(Tuple::origin_point(), Tuple::new_vector(0.0, 0.0, 1.0));
+    let xs = g.intersections(&r);
+    assert_eq!((xs[0].t, xs[1].t), (9.0, 11.0));
+    let s = g.children()[0].as_ref();
+    assert_eq!(s.world_to_object(&Tuple::new_point(0.0, 0.0, 9.0)), Tuple::new_point(0.0, 0.0, -1.0));
+    assert_eq!(s.surface_normal(&Tuple::new_point(0.0, 0.0, 9.0)), Tuple::new_vector(0.0, 0.0, -1.0));
+}
+
+#[test]
+fn test_group_nested_group_moved_after_being_added() {
+    let mut g1 = nested_groups(Matrix4::scaling(2.0, 2.0, 2.0));
+    g1.set_transform(Matrix4::identity()).unwrap();
+    // without the rotation, the sphere sits at x = 10 with radius 2
+    let s = innermost(&g1);
+    assert_eq!(s.world_to_object(&Tuple::new_point(12.0, 0.0, 0.0)), Tuple::new_point(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_group_pattern_on_child_uses_group_transform() {
+    let mut s = Sphere::new_unit();
+    s.mut_material().set_pattern(StripePattern::new(Color::white(1.0), Color::new(0.0, 0.0, 0.0)));
+    let mut g = Group::new();
+    g.set_transform(Matrix4::scaling(2.0, 2.0, 2.0)).unwrap();
+    g.add_child(Box::new(s));
+    let s = g.children()[0].as_ref();
+    let pattern = s.material().pattern.as_ref().unwrap();
+    // x = 1.5 in the world is x = 0.75 on the sphere: still the first stripe
+    assert_eq!(pattern.color_at_object(s, &Tuple::new_point(1.5, 0.0, 0.0)), Color::white(1.0));
+    assert_eq!(pattern.color_at_object(s, &Tuple::new_point(2.5, 0.0, 0.0)), Color::new(0.0, 0.0, 0.0));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_group_bounds_contain_children() {
+    let mut s = Sphere::new_unit();
+    s.set_transform(Matrix4::translation(2.0, 5.0, -3.0) * Matrix4::scaling(2.0, 2.0, 2.0)).unwrap();
+    let mut c = Cylinder::new_truncated(-2.0, 2.0, false);
+    c.set_transform(Matrix4::translation(-4.0, -1.0, 4.0) * Matrix4::scaling(0.5, 1.0, 0.5)).unwrap();
+    let mut g = Group::new();
+    g.add_child(Box::new(s));
+    g.add_child(Box::new(c));
+    assert_eq!(g.bounds(), BoundingBox::new(Tuple::new_point(-4.5, -3.0, -5.0), Tuple::new_point(4.0, 7.0, 4.5)));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_ray_missing_group_box_skips_children() {
+    let mut g = Group::new();
+    g.add_child(Box::new(TestShape::new()));
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 1.0, 0.0));
+    g.intersections(&r);
+    assert!(saved_ray(&g, 0).is_none());
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_ray_hitting_group_box_tests_children() {
+    let mut g = Group::new();
+    g.add_child(Box::new(TestShape::new()));
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    g.intersections(&r);
+    assert!(saved_ray(&g, 0).is_some());
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_partition_children() {
+    let mut g = Group::new();
+    g.add_child(translated_sphere(-2.0, 0.0, 0.0));
+    g.add_child(translated_sphere(2.0, 0.0, 0.0));
+    g.add_child(Box::new(Sphere::new_unit()));
+    let (left, right) = g.partition_children();
+    assert_eq!(g.len(), 1);
+    assert_eq!(g.children()[0].transformation(), &Matrix4::identity());
+    assert_eq!(left.len(), 1);
+    assert_eq!(left[0].transformation(), &Matrix4::translation(-2.0, 0.0, 0.0));
+    assert_eq!(right.len(), 1);
+    assert_eq!(right[0].transformation(), &Matrix4::translation(2.0, 0.0, 0.0));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_divide_partitions_children() {
+    let mut s3 = Sphere::new_unit();
+    s3.set_transform(Matrix4::scaling(4.0, 4.0, 4.0)).unwrap();
+    let mut g = Group::new();
+    g.add_child(translated_sphere(-2.0, -2.0, 0.0));
+    g.add_child(translated_sphere(-2.0, 2.0, 0.0));
+    g.add_child(Box::new(s3));
+    g.divide(1);
+    assert_eq!(g.len(), 2);
+    assert_eq!(g.children()[0].transformation(), &Matrix4::scaling(4.0, 4.0, 4.0));
+    let sub = subgroup(&g, 1);
+    assert_eq!(sub.len(), 2);
+    assert_eq!(subgroup(sub, 0).children()[0].transformation(), &Matrix4::translation(-2.0, -2.0, 0.0));
+    assert_eq!(subgroup(sub, 1).children()[0].transformation(), &Matrix4::translation(-2.0, 2.0, 0.0));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_divide_group_with_too_few_children() {
+    let mut sub = Group::new();
+    sub.add_child(translated_sphere(-2.0, 0.0, 0.0));
+    sub.add_child(translated_sphere(2.0, 1.0, 0.0));
+    sub.add_child(translated_sphere(2.0, -1.0, 0.0));
+    let mut g = Group::new();
+    g.add_child(Box::new(sub));
+    g.add_child(Box::new(Sphere::new_unit()));
+    g.divide(3);
+    assert_eq!(g.len(), 2);
+    assert!(g.children()[1].as_any().is::<Sphere>());
+    let sub = subgroup(&g, 0);
+    assert_eq!(sub.len(), 2);
+    assert_eq!(subgroup(sub, 0).len(), 1);
+    assert_eq!(subgroup(sub, 1).len(), 2);
+}
+
+#[test]
+fn test_divide_keeps_the_same_hits() {
+    // a row of spheres in a moved group; dividing it changes the hierarchy but not what's hit
+    let make = || {
+        let mut g = Group::new();
+        g.set_transform(Matrix4::translation(0.0, 1.0, 0.0)).unwrap();
+        for i in 0..10 {
+            g.add_child(translated_sphere(i as f64 * 3.0, 0.0, 0.0));
+        }
+        g
+    };
+    let mut divided = make();
+    divided.divide(2);
+    assert!(divided.len() < 10);
+    for i in 0..10 {
+        let r = Ray::new(Tuple::new_point(i as f64 * 3.0, 1.5, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+        let before: Vec<f64> = make().intersections(&r).iter().map(|x| x.t).collect();
+        let after: Vec<f64> = divided.intersections(&r).iter().map(|x| x.t).collect();
+        assert_eq!(before, after);
+        assert_eq!(after.len(), 2);
+        let hit = &divided.intersections(&r)[0];
+        let point = r.position(&hit.t);
+        assert_eq!(hit.object.surface_normal(&point), make().children()[i].surface_normal(&point));
+    }
+}
+
+#[test]
+fn test_divide_does_not_loop_on_identical_children() {
+    // cones cut off at their tip are a single point, so the box has no size and every child
+    // fits in both halves
+    let mut g = Group::new();
+    for _ in 0..4 {
+        g.add_child(Box::new(Cone::new_truncated(0.0, 0.0, false)));
+    }
+    g.divide(1);
+    assert_eq!(g.len(), 4);
+}
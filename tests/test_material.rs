@@ -1,4 +1,4 @@
-use book_renderer::{color::Color, material::Material, tuple::Tuple, light::Light};
+use book_renderer::{color::{Color, BLACK}, material::Material, tuple::Tuple, light::Light, sphere::Sphere, pattern::StripePattern};
 
 #[test]
 fn test_book_default_material() {
@@ -8,59 +8,107 @@ fn test_book_default_material() {
     assert_eq!(m.diffuse, 0.9);
     assert_eq!(m.specular, 0.9);
     assert_eq!(m.shininess, 200.0);
+    assert_eq!(m.reflective, 0.0);
+    assert_eq!(m.transparency, 0.0);
+    assert_eq!(m.refractive_index, 1.0);
+    assert!(m.pattern.is_none());
 }
 
 #[test]
 fn test_book_light_eye_between_light_and_surface() {
     let m = Material::default();
+    let object = Sphere::new_unit();
     let position = Tuple::origin_point();
     let eyev = Tuple::new_vector(0.0, 0.0, -1.0);
     let normalv = Tuple::new_vector(0.0, 0.0, -1.0);
     let light = Light::new_point(Tuple::new_point(0.0, 0.0, -10.0), Color::white(1.0));
-    let result = m.light(&light, &position, &eyev, &normalv);
+    let result = m.light(&object, &light, &position, &eyev, &normalv, false);
     assert_eq!(result, Color::white(1.9));
 }
 
 #[test]
 fn test_book_light_eye_between_light_and_surface_eye_offset_45() {
     let m = Material::default();
+    let object = Sphere::new_unit();
     let position = Tuple::origin_point();
     let eyev = Tuple::new_vector(0.0, 2.0_f64.sqrt()/2.0, -2.0_f64.sqrt()/2.0);
     let normalv = Tuple::new_vector(0.0, 0.0, -1.0);
     let light = Light::new_point(Tuple::new_point(0.0, 0.0, -10.0), Color::white(1.0));
-    let result = m.light(&light, &position, &eyev, &normalv);
+    let result = m.light(&object, &light, &position, &eyev, &normalv, false);
     assert_eq!(result, Color::white(1.0));
 }
 
 #[test]
 fn test_book_light_eye_between_light_and_surface_light_offset_45() {
     let m = Material::default();
+    let object = Sphere::new_unit();
     let position = Tuple::origin_point();
     let eyev = Tuple::new_vector(0.0, 0.0, -1.0);
     let normalv = Tuple::new_vector(0.0, 0.0, -1.0);
     let light = Light::new_point(Tuple::new_point(0.0, 10.0, -10.0), Color::white(1.0));
-    let result = m.light(&light, &position, &eyev, &normalv);
+    let result = m.light(&object, &light, &position, &eyev, &normalv, false);
     assert_eq!(result, Color::white(0.7364));
 }
 
 #[test]
 fn test_book_light_eye_and_surface_90_from_each_other() {
     let m = Material::default();
+    let object = Sphere::new_unit();
     let position = Tuple::origin_point();
     let eyev = Tuple::new_vector(0.0, -2.0_f64.sqrt()/2.0, -2.0_f64.sqrt()/2.0);
     let normalv = Tuple::new_vector(0.0, 0.0, -1.0);
     let light = Light::new_point(Tuple::new_point(0.0, 10.0, -10.0), Color::white(1.0));
-    let result = m.light(&light, &position, &eyev, &normalv);
+    let result = m.light(&object, &light, &position, &eyev, &normalv, false);
     assert_eq!(result, Color::white(1.6364));
 }
 
 #[test]
 fn test_book_light_light_behind_surface() {
     let m = Material::default();
+    let object = Sphere::new_unit();
     let position = Tuple::origin_point();
     let eyev = Tuple::new_vector(0.0, 0.0, -1.0);
     let normalv = Tuple::new_vector(0.0, 0.0, -1.0);
     let light = Light::new_point(Tuple::new_point(0.0, 0.0, 10.0), Color::white(1.0));
-    let result = m.light(&light, &position, &eyev, &normalv);
+    let result = m.light(&object, &light, &position, &eyev, &normalv, false);
     assert_eq!(result, Color::white(0.1));
 }
+
+// pg 110
+#[test]
+fn test_book_light_surface_in_shadow() {
+    let m = Material::default();
+    let object = Sphere::new_unit();
+    let position = Tuple::origin_point();
+    let eyev = Tuple::new_vector(0.0, 0.0, -1.0);
+    let normalv = Tuple::new_vector(0.0, 0.0, -1.0);
+    let light = Light::new_point(Tuple::new_point(0.0, 0.0, -10.0), Color::white(1.0));
+    let result = m.light(&object, &light, &position, &eyev, &normalv, true);
+    assert_eq!(result, Color::white(0.1));
+}
+
+// pg 129
+#[test]
+fn test_book_light_with_pattern() {
+    let mut m = Material { ambient: 1.0, diffuse: 0.0, specular: 0.0, ..Material::default() };
+    m.set_pattern(StripePattern::new(Color::white(1.0), BLACK));
+    let object = Sphere::new_unit();
+    let eyev = Tuple::new_vector(0.0, 0.0, -1.0);
+    let normalv = Tuple::new_vector(0.0, 0.0, -1.0);
+    let light = Light::new_point(Tuple::new_point(0.0, 0.0, -10.0), Color::white(1.0));
+    let c1 = m.light(&object, &light, &Tuple::new_point(0.9, 0.0, 0.0), &eyev, &normalv, false);
+    let c2 = m.light(&object, &light, &Tuple::new_point(1.1, 0.0, 0.0), &eyev, &normalv, false);
+    assert_eq!(c1, Color::white(1.0));
+    assert_eq!(c2, BLACK);
+}
+
+#[test]
+fn test_materials_with_different_patterns_differ() {
+    let mut m1 = Material::default();
+    m1.set_pattern(StripePattern::new(Color::white(1.0), BLACK));
+    let mut m2 = Material::default();
+    m2.set_pattern(StripePattern::new(Color::white(1.0), BLACK));
+    assert_ne!(m1, m2);
+    assert_eq!(m1, m1.clone());
+    assert_ne!(m1, Material::default());
+}
@@ -1,6 +1,6 @@
-use std::error::Error;
+use std::{error::Error, str::FromStr};
 use proptest::{prelude::*, num::f64::{POSITIVE, NEGATIVE,NORMAL}};
-use book_renderer::{matrix::Matrix, tuple::Tuple, util::feq};
+use book_renderer::{matrix::Matrix, matrix4::Matrix4, tuple::Tuple, util::feq};
 
 #[test]
 fn test_book_from_str_matrix_4_4() -> Result<(), Box<dyn Error>> {
@@ -447,8 +447,8 @@ fn test_book_default_view_transform() -> Result<(), Box<dyn Error>> {
     let from = Tuple::new_point(0.0, 0.0, 0.0);
     let to = Tuple::new_point(0.0, 0.0, -1.0);
     let up = Tuple::new_vector(0.0, 1.0, 0.0);
-    let t = Matrix::view_transform(&from, &to, &up)?;
-    assert_eq!(t, Matrix::identity(4));
+    let t = Matrix4::view_transform(&from, &to, &up)?;
+    assert_eq!(t, Matrix4::identity());
     Ok(())
 }
 
@@ -458,8 +458,8 @@ fn test_book_view_transform_positive_z() -> Result<(), Box<dyn Error>> {
     let from = Tuple::new_point(0.0, 0.0, 0.0);
     let to = Tuple::new_point(0.0, 0.0, 1.0);
     let up = Tuple::new_vector(0.0, 1.0, 0.0);
-    let t = Matrix::view_transform(&from, &to, &up)?;
-    assert_eq!(t, Matrix::scaling(-1.0, 1.0, -1.0));
+    let t = Matrix4::view_transform(&from, &to, &up)?;
+    assert_eq!(t, Matrix4::scaling(-1.0, 1.0, -1.0));
     Ok(())
 }
 
@@ -469,8 +469,8 @@ fn test_book_view_transform_world() -> Result<(), Box<dyn Error>> {
     let from = Tuple::new_point(0.0, 0.0, 8.0);
     let to = Tuple::new_point(0.0, 0.0, 0.0);
     let up = Tuple::new_vector(0.0, 1.0, 0.0);
-    let t = Matrix::view_transform(&from, &to, &up)?;
-    assert_eq!(t, Matrix::translation(0.0, 0.0, -8.0));
+    let t = Matrix4::view_transform(&from, &to, &up)?;
+    assert_eq!(t, Matrix4::translation(0.0, 0.0, -8.0));
     Ok(())
 } 
 
@@ -480,8 +480,8 @@ fn test_book_view_transform_multiple() -> Result<(), Box<dyn Error>> {
     let from = Tuple::new_point(1.0, 3.0, 2.0);
     let to = Tuple::new_point(4.0, -2.0, 8.0);
     let up = Tuple::new_vector(1.0, 1.0, 0.0);
-    let t = Matrix::view_transform(&from, &to, &up)?;
-    assert_eq!(t, Matrix::from_str(4, 4, "
+    let t = Matrix4::view_transform(&from, &to, &up)?;
+    assert_eq!(t, Matrix4::from_str("
     -0.50709 | 0.50709 |  0.67612 | -2.36643
      0.76772 | 0.60609 |  0.12122 | -2.82843
     -0.35857 | 0.59761 | -0.71714 |  0.00000
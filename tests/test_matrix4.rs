@@ -0,0 +1,187 @@
+use std::{error::Error, str::FromStr};
+use proptest::prelude::*;
+use book_renderer::{matrix::Matrix, matrix4::Matrix4, tuple::Tuple, util::feq_precision};
+
+#[test]
+fn test_from_str_matrix4() -> Result<(), Box<dyn Error>> {
+    let m = Matrix4::from_str("
+    01,2,3,4
+    5.5,6.5,7.5,8.5
+    9,10,11,12
+    13.5,14.5,15.5,16.5")?;
+    assert_eq!(m[(0,0)], 1.0);
+    assert_eq!(m[(0,3)], 4.0);
+    assert_eq!(m[(1,2)], 7.5);
+    assert_eq!(m[(3,2)], 15.5);
+    Ok(())
+}
+
+#[test]
+fn test_book_matrix4_mult() -> Result<(), Box<dyn Error>> {
+    let m1 = Matrix4::from_str("
+    | 1 | 2 | 3 | 4 |
+    | 5 | 6 | 7 | 8 |
+    | 9 | 8 | 7 | 6 |
+    | 5 | 4 | 3 | 2 |
+    ")?;
+    let m2 = Matrix4::from_str("
+    | -2 | 1 | 2 |  3 |
+    |  3 | 2 | 1 | -1 |
+    |  4 | 3 | 6 |  5 |
+    |  1 | 2 | 7 |  8 |
+    ")?;
+    assert_eq!(m1 * m2, Matrix4::from_str("
+    | 20 | 22 |  50 |  48 |
+    | 44 | 54 | 114 | 108 |
+    | 40 | 58 | 110 | 102 |
+    | 16 | 26 |  46 |  42 |
+    ")?);
+    Ok(())
+}
+
+#[test]
+fn test_book_matrix4_tuple_mult() -> Result<(), Box<dyn Error>> {
+    let m = Matrix4::from_str("
+    1 | 2 | 3 | 4
+    2 | 4 | 4 | 2
+    8 | 6 | 4 | 1
+    0 | 0 | 0 | 1
+    ")?;
+    assert_eq!(m * Tuple::new_point(1.0, 2.0, 3.0), Tuple::new_point(18.0, 24.0, 33.0));
+    Ok(())
+}
+
+#[test]
+fn test_book_matrix4_identity() -> Result<(), Box<dyn Error>> {
+    let m = Matrix4::from_str("
+    0 | 1 |  2 | 4
+    1 | 2 |  4 | 6
+    2 | 4 |  0 | 16
+    4 | 8 | 16 | 32
+    ")?;
+    assert_eq!(Matrix4::identity() * m, m);
+    let t = Tuple { x: 1.0, y: 2.0, z: 3.0, w: 4.0 };
+    assert_eq!(Matrix4::identity() * t, t);
+    Ok(())
+}
+
+#[test]
+fn test_book_matrix4_transpose() -> Result<(), Box<dyn Error>> {
+    let m1 = Matrix4::from_str("
+    0 | 9 | 3 | 0
+    9 | 8 | 0 | 8
+    1 | 8 | 5 | 3
+    0 | 0 | 5 | 8
+    ")?;
+    let m2 = Matrix4::from_str("
+    0 | 9 | 1 | 0
+    9 | 8 | 8 | 0
+    3 | 0 | 5 | 5
+    0 | 8 | 3 | 8
+    ")?;
+    assert_eq!(m1.transpose(), m2);
+    assert_eq!(Matrix4::identity().transpose(), Matrix4::identity());
+    Ok(())
+}
+
+#[test]
+fn test_book_matrix4_determinant() -> Result<(), Box<dyn Error>> {
+    let m = Matrix4::from_str("
+    -2 | -8 |  3 |  5
+    -3 |  1 |  7 |  3
+     1 |  2 | -9 |  6
+    -6 |  7 |  7 | -9
+    ")?;
+    assert_eq!(m.determinant(), -4071.0);
+    Ok(())
+}
+
+#[test]
+fn test_book_matrix4_not_invertible() -> Result<(), Box<dyn Error>> {
+    let m = Matrix4::from_str("
+    -4 |  2 | -2 | -3
+     9 |  6 |  2 |  6
+     0 | -5 |  1 | -5
+     0 |  0 |  0 |  0
+    ")?;
+    assert_eq!(m.determinant(), 0.0);
+    assert!(!m.is_invertible());
+    assert!(m.inverse().is_err());
+    Ok(())
+}
+
+#[test]
+fn test_book_matrix4_inverse() -> Result<(), Box<dyn Error>> {
+    let a = Matrix4::from_str("
+    -5 |  2 |  6 | -8
+     1 | -5 |  1 |  8
+     7 |  7 | -6 | -7
+     1 | -3 |  7 |  4
+    ")?;
+    let b = a.inverse()?;
+    assert_eq!(a.determinant(), 532.0);
+    assert_eq!(b[(3,2)], -160.0/532.0);
+    assert_eq!(b[(2,3)], 105.0/532.0);
+    assert_eq!(b, Matrix4::from_str("
+     0.21805 |  0.45113 |  0.24060 | -0.04511
+    -0.80827 | -1.45677 | -0.44361 |  0.52068
+    -0.07895 | -0.22368 | -0.05263 |  0.19737
+    -0.52256 | -0.81391 | -0.30075 |  0.30639
+    ")?);
+    Ok(())
+}
+
+#[test]
+fn test_book_matrix4_inverse_mult_property() -> Result<(), Box<dyn Error>> {
+    let a = Matrix4::from_str("
+     3 | -9 |  7 |  3
+     3 | -8 |  2 | -9
+    -4 |  4 |  4 |  1
+    -6 |  5 | -1 |  1
+    ")?;
+    let b = Matrix4::from_str("
+    8 |  2 | 2 | 2
+    3 | -1 | 7 | 0
+    7 |  0 | 5 | 4
+    6 | -2 | 0 | 5
+    ")?;
+    let c = a * b;
+    assert_eq!(c * b.inverse()?, a);
+    Ok(())
+}
+
+#[test]
+fn test_matrix4_round_trips_through_matrix() -> Result<(), Box<dyn Error>> {
+    let m = Matrix4::from_str("
+     8 | -5 |  9 |  2
+     7 |  5 |  6 |  1
+    -6 |  0 |  9 |  6
+    -3 |  0 | -9 | -4
+    ")?;
+    let general = Matrix::from(&m);
+    assert_eq!(Matrix4::from(&general), m);
+    assert_eq!(Matrix4::from(&general.inverse()?), m.inverse()?);
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn matches_general_matrix(values in prop::array::uniform4(prop::array::uniform4(-100.0..100.0f64))) {
+        let m = Matrix4::new(values);
+        let general = Matrix::from(&m);
+        prop_assert!(feq_precision(&m.determinant(), &general.determinant(), 3));
+        prop_assume!(m.determinant().abs() > 1000.0);
+        prop_assert_eq!(m.inverse()?, Matrix4::from(&general.inverse()?));
+    }
+}
+
+#[test]
+fn test_matrix4_inverse_keeps_points_as_points() -> Result<(), Box<dyn Error>> {
+    let from = Tuple::new_point(0.0, 1.5, -5.0);
+    let to = Tuple::new_point(0.0, 1.0, 0.0);
+    let up = Tuple::new_vector(0.0, 1.0, 0.0);
+    let inv = Matrix4::view_transform(&from, &to, &up)?.inverse()?;
+    assert!((inv * Tuple::new_point(0.3, -0.2, -1.0)).is_point());
+    assert!((inv * Tuple::new_vector(0.3, -0.2, -1.0)).is_vector());
+    Ok(())
+}
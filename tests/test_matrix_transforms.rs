@@ -1,17 +1,17 @@
 use std::{error::Error, f64::consts::PI};
 // use proptest::{prelude::*, num::f64::{POSITIVE, NEGATIVE,NORMAL}};
-use book_renderer::{matrix::Matrix, tuple::Tuple, util::Float};
+use book_renderer::{matrix4::Matrix4, tuple::Tuple, util::Float};
 
 #[test]
 fn test_book_translation_mult() {
-    let transform = Matrix::translation(5.0, -3.0, 2.0);
+    let transform = Matrix4::translation(5.0, -3.0, 2.0);
     let p = Tuple::new_point(-3.0, 4.0, 5.0);
     assert_eq!(transform * p, Tuple::new_point(2.0, 1.0, 7.0));
 }
 
 #[test]
 fn test_book_inverse_translation_mult() -> Result<(), Box<dyn Error>> {
-    let transform = Matrix::translation(5.0, -3.0, 2.0);
+    let transform = Matrix4::translation(5.0, -3.0, 2.0);
     let inv = transform.inverse()?;
     let p = Tuple::new_point(-3.0, 4.0, 5.0);
     assert_eq!(inv * p, Tuple::new_point(-8.0, 7.0, 3.0));
@@ -20,28 +20,28 @@ fn test_book_inverse_translation_mult() -> Result<(), Box<dyn Error>> {
 
 #[test]
 fn test_book_transform_vector() {
-    let transform = Matrix::translation(5.0, -3.0, 2.0);
+    let transform = Matrix4::translation(5.0, -3.0, 2.0);
     let v = Tuple::new_vector(-3.0, 4.0, 5.0);
     assert_eq!(transform * v, v);
 }
 
 #[test]
 fn test_book_scale_point() {
-    let transform = Matrix::scaling(2.0, 3.0, 4.0);
+    let transform = Matrix4::scaling(2.0, 3.0, 4.0);
     let p = Tuple::new_point(-4.0, 6.0, 8.0);
     assert_eq!(transform * p, Tuple::new_point(-8.0, 18.0, 32.0));
 }
 
 #[test]
 fn test_book_scale_vector() {
-    let transform = Matrix::scaling(2.0, 3.0, 4.0);
+    let transform = Matrix4::scaling(2.0, 3.0, 4.0);
     let v = Tuple::new_vector(-4.0, 6.0, 8.0);
     assert_eq!(transform * v, Tuple::new_vector(-8.0, 18.0, 32.0));
 }
 
 #[test]
 fn test_book_scale_vector_inverse() -> Result<(), Box<dyn Error>> {
-    let transform = Matrix::scaling(2.0, 3.0, 4.0);
+    let transform = Matrix4::scaling(2.0, 3.0, 4.0);
     let inv = transform.inverse()?;
     let v = Tuple::new_vector(-4.0, 6.0, 8.0);
     assert_eq!(inv * v, Tuple::new_vector(-2.0, 2.0, 2.0));
@@ -50,7 +50,7 @@ fn test_book_scale_vector_inverse() -> Result<(), Box<dyn Error>> {
 
 #[test]
 fn test_book_reflect_point() {
-    let transform = Matrix::scaling(-1.0, 1.0, 1.0);
+    let transform = Matrix4::scaling(-1.0, 1.0, 1.0);
     let p = Tuple::new_point(2.0, 3.0, 4.0);
     assert_eq!(transform * p, Tuple::new_point(-2.0, 3.0, 4.0));
 }
@@ -58,8 +58,8 @@ fn test_book_reflect_point() {
 #[test]
 fn test_book_rotate_x_axis() {
     let p = Tuple::new_point(0.0, 1.0, 0.0);
-    let half_quarter = Matrix::rotation_x(PI / 4.0);
-    let full_quarter = Matrix::rotation_x(PI / 2.0);
+    let half_quarter = Matrix4::rotation_x(PI / 4.0);
+    let full_quarter = Matrix4::rotation_x(PI / 2.0);
     assert_eq!(half_quarter * p, Tuple::new_point(0.0, Float::sqrt(2.0)/2.0, Float::sqrt(2.0)/2.0));
     assert_eq!(full_quarter * p, Tuple::new_point(0.0, 0.0, 1.0));
 }
@@ -67,7 +67,7 @@ fn test_book_rotate_x_axis() {
 #[test]
 fn test_book_inverse_x_rotation() -> Result<(), Box<dyn Error>> {
     let p = Tuple::new_point(0.0, 1.0, 0.0);
-    let half_quarter = Matrix::rotation_x(PI / 4.0);
+    let half_quarter = Matrix4::rotation_x(PI / 4.0);
     let inv = half_quarter.inverse()?;
     assert_eq!(inv * p, Tuple::new_point(0.0, Float::sqrt(2.0)/2.0, -Float::sqrt(2.0)/2.0));
     Ok(())
@@ -76,8 +76,8 @@ fn test_book_inverse_x_rotation() -> Result<(), Box<dyn Error>> {
 #[test]
 fn test_book_y_rotation() {
     let p = Tuple::new_point(0.0, 0.0, 1.0);
-    let half_quarter = Matrix::rotation_y(PI/4.0);
-    let full_quarter = Matrix::rotation_y(PI/2.0);
+    let half_quarter = Matrix4::rotation_y(PI/4.0);
+    let full_quarter = Matrix4::rotation_y(PI/2.0);
     assert_eq!(half_quarter * p, Tuple::new_point(Float::sqrt(2.0)/2.0, 0.0, Float::sqrt(2.0)/2.0));
     assert_eq!(full_quarter * p, Tuple::new_point(1.0, 0.0, 0.0));
 }
@@ -85,8 +85,8 @@ fn test_book_y_rotation() {
 #[test]
 fn test_book_z_rotation() {
     let p = Tuple::new_point(0.0, 1.0, 0.0);
-    let half_quarter = Matrix::rotation_z(PI/4.0);
-    let full_quarter = Matrix::rotation_z(PI/2.0);
+    let half_quarter = Matrix4::rotation_z(PI/4.0);
+    let full_quarter = Matrix4::rotation_z(PI/2.0);
     assert_eq!(half_quarter * p, Tuple::new_point(-Float::sqrt(2.0)/2.0, Float::sqrt(2.0)/2.0, 0.0));
     assert_eq!(full_quarter * p, Tuple::new_point(-1.0, 0.0, 0.0));
 }
@@ -96,7 +96,7 @@ macro_rules! shearing_tests {
         $(
             #[test]
             fn $test_name() {
-                let transform = Matrix::shear($shear.0, $shear.1, $shear.2, $shear.3, $shear.4, $shear.5);
+                let transform = Matrix4::shear($shear.0, $shear.1, $shear.2, $shear.3, $shear.4, $shear.5);
                 let p = Tuple::new_point(2.0, 3.0, 4.0);
                 assert_eq!(transform * p, Tuple::new_point($expected.0, $expected.1, $expected.2));
             }
@@ -115,9 +115,9 @@ shearing_tests! {
 #[test]
 fn test_book_transform_seq() {
     let p = Tuple::new_point(1.0, 0.0, 1.0);
-    let a = Matrix::rotation_x(PI/2.0);
-    let b = Matrix::scaling(5.0, 5.0, 5.0);
-    let c = Matrix::translation(10.0, 5.0, 7.0);
+    let a = Matrix4::rotation_x(PI/2.0);
+    let b = Matrix4::scaling(5.0, 5.0, 5.0);
+    let c = Matrix4::translation(10.0, 5.0, 7.0);
     let p2 = a * p;
     assert_eq!(p2, Tuple::new_point(1.0, -1.0, 0.0));
     let p3 = b * p2;
@@ -129,9 +129,39 @@ fn test_book_transform_seq() {
 #[test]
 fn test_book_transform_chain() {
     let p = Tuple::new_point(1.0, 0.0, 1.0);
-    let a = Matrix::rotation_x(PI/2.0);
-    let b = Matrix::scaling(5.0, 5.0, 5.0);
-    let c = Matrix::translation(10.0, 5.0, 7.0);
+    let a = Matrix4::rotation_x(PI/2.0);
+    let b = Matrix4::scaling(5.0, 5.0, 5.0);
+    let c = Matrix4::translation(10.0, 5.0, 7.0);
     let t = c * b * a;
     assert_eq!(t * p, Tuple::new_point(15.0, 0.0, 7.0));
-}
\ No newline at end of file
+}
+#[test]
+fn test_fluent_chain_reads_in_order() {
+    let p = Tuple::new_point(1.0, 0.0, 1.0);
+    let t = Matrix4::identity()
+        .rotate_x(PI/2.0)
+        .scale(5.0, 5.0, 5.0)
+        .translate(10.0, 5.0, 7.0);
+    assert_eq!(t, Matrix4::translation(10.0, 5.0, 7.0) * Matrix4::scaling(5.0, 5.0, 5.0) * Matrix4::rotation_x(PI/2.0));
+    assert_eq!(t * p, Tuple::new_point(15.0, 0.0, 7.0));
+}
+
+#[test]
+fn test_fluent_matches_constructors() {
+    let id = Matrix4::identity();
+    assert_eq!(id.translate(1.0, 2.0, 3.0), Matrix4::translation(1.0, 2.0, 3.0));
+    assert_eq!(id.scale(1.0, 2.0, 3.0), Matrix4::scaling(1.0, 2.0, 3.0));
+    assert_eq!(id.rotate_x(0.5), Matrix4::rotation_x(0.5));
+    assert_eq!(id.rotate_y(0.5), Matrix4::rotation_y(0.5));
+    assert_eq!(id.rotate_z(0.5), Matrix4::rotation_z(0.5));
+    assert_eq!(id.skew(1.0, 2.0, 3.0, 4.0, 5.0, 6.0), Matrix4::shear(1.0, 2.0, 3.0, 4.0, 5.0, 6.0));
+}
+
+#[test]
+fn test_fluent_order_matters() {
+    let p = Tuple::new_point(1.0, 0.0, 0.0);
+    let scale_then_move = Matrix4::identity().scale(2.0, 2.0, 2.0).translate(1.0, 0.0, 0.0);
+    let move_then_scale = Matrix4::identity().translate(1.0, 0.0, 0.0).scale(2.0, 2.0, 2.0);
+    assert_eq!(scale_then_move * p, Tuple::new_point(3.0, 0.0, 0.0));
+    assert_eq!(move_then_scale * p, Tuple::new_point(4.0, 0.0, 0.0));
+}
@@ -0,0 +1,220 @@
+use std::{error::Error, str::FromStr};
+
+use book_renderer::{
+    obj::ObjFile, triangle::Triangle, smooth_triangle::SmoothTriangle, intersectable::Intersectable,
+    tuple::Tuple,
+};
+
+fn parse(contents: &str) -> ObjFile {
+    ObjFile::from_str(contents).unwrap()
+}
+
+fn triangle(shape: &dyn Intersectable) -> &Triangle {
+    shape.as_any().downcast_ref::<Triangle>().unwrap()
+}
+
+fn smooth_triangle(shape: &dyn Intersectable) -> &SmoothTriangle {
+    shape.as_any().downcast_ref::<SmoothTriangle>().unwrap()
+}
+
+// pg 213
+#[test]
+fn test_book_ignoring_unrecognized_lines() {
+    let obj = parse("There was a young lady named Bright
+who traveled much faster than light.
+She set out one day
+in a relative way,
+and came back the previous night.
+");
+    assert_eq!(obj.ignored, 5);
+    assert!(obj.vertices.is_empty());
+}
+
+// pg 213
+#[test]
+fn test_book_vertex_records() {
+    let obj = parse("v -1 1 0
+v -1.0000 0.5000 0.0000
+v 1 0 0
+v 1 1 0
+");
+    assert_eq!(obj.vertices, vec![
+        Tuple::new_point(-1.0, 1.0, 0.0),
+        Tuple::new_point(-1.0, 0.5, 0.0),
+        Tuple::new_point(1.0, 0.0, 0.0),
+        Tuple::new_point(1.0, 1.0, 0.0),
+    ]);
+}
+
+// pg 214
+#[test]
+fn test_book_parsing_triangle_faces() {
+    let obj = parse("v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+f 1 2 3
+f 1 3 4
+");
+    let t1 = triangle(obj.default_group[0].as_ref());
+    let t2 = triangle(obj.default_group[1].as_ref());
+    assert_eq!((t1.p1(), t1.p2(), t1.p3()), (&obj.vertices[0], &obj.vertices[1], &obj.vertices[2]));
+    assert_eq!((t2.p1(), t2.p2(), t2.p3()), (&obj.vertices[0], &obj.vertices[2], &obj.vertices[3]));
+}
+
+// pg 215
+#[test]
+fn test_book_triangulating_polygons() {
+    let obj = parse("v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+v 0 2 0
+
+f 1 2 3 4 5
+");
+    assert_eq!(obj.default_group.len(), 3);
+    let t3 = triangle(obj.default_group[2].as_ref());
+    assert_eq!((t3.p1(), t3.p2(), t3.p3()), (&obj.vertices[0], &obj.vertices[3], &obj.vertices[4]));
+}
+
+// pg 217
+#[test]
+fn test_book_triangles_in_groups() {
+    let obj = parse("v -1 1 0
+v -1 0 0
+v 1 0 0
+v 1 1 0
+
+g FirstGroup
+f 1 2 3
+g SecondGroup
+f 1 3 4
+");
+    assert!(obj.default_group.is_empty());
+    let t1 = triangle(obj.group("FirstGroup").unwrap()[0].as_ref());
+    let t2 = triangle(obj.group("SecondGroup").unwrap()[0].as_ref());
+    assert_eq!(t1.p3(), &obj.vertices[2]);
+    assert_eq!(t2.p3(), &obj.vertices[3]);
+    assert!(obj.group("ThirdGroup").is_none());
+}
+
+// pg 223
+#[test]
+fn test_book_vertex_normal_records() {
+    let obj = parse("vn 0 0 1
+vn 0.707 0 -0.707
+vn 1 2 3
+");
+    assert_eq!(obj.normals, vec![
+        Tuple::new_vector(0.0, 0.0, 1.0),
+        Tuple::new_vector(0.707, 0.0, -0.707),
+        Tuple::new_vector(1.0, 2.0, 3.0),
+    ]);
+}
+
+// pg 224
+#[test]
+fn test_book_faces_with_normals() {
+    let obj = parse("v 0 1 0
+v -1 0 0
+v 1 0 0
+
+vn -1 0 0
+vn 1 0 0
+vn 0 1 0
+
+f 1//3 2//1 3//2
+f 1/0/3 2/102/1 3/14/2
+");
+    for shape in &obj.default_group {
+        let t = smooth_triangle(shape.as_ref());
+        assert_eq!((t.p1(), t.p2(), t.p3()), (&obj.vertices[0], &obj.vertices[1], &obj.vertices[2]));
+        assert_eq!((t.n1(), t.n2(), t.n3()), (&obj.normals[2], &obj.normals[0], &obj.normals[1]));
+    }
+    assert_eq!(obj.default_group.len(), 2);
+}
+
+#[test]
+fn test_obj_reused_group_name_adds_to_group() {
+    let obj = parse("v 0 1 0
+v -1 0 0
+v 1 0 0
+g a
+f 1 2 3
+g b
+f 1 2 3
+g a
+f 3 2 1
+");
+    assert_eq!(obj.groups.len(), 2);
+    assert_eq!(obj.group("a").unwrap().len(), 2);
+    assert_eq!(obj.into_shapes().len(), 3);
+}
+
+#[test]
+fn test_obj_negative_indices_count_back() {
+    let obj = parse("v 0 1 0
+v -1 0 0
+v 1 0 0
+f -3 -2 -1
+");
+    let t = triangle(obj.default_group[0].as_ref());
+    assert_eq!((t.p1(), t.p2(), t.p3()), (&obj.vertices[0], &obj.vertices[1], &obj.vertices[2]));
+}
+
+#[test]
+fn test_obj_malformed_lines_are_skipped() {
+    let obj = parse("v 0 1 0
+v -1 zero 0
+v -1 0 0
+v 1 0 0
+f 1 2 7
+f 1 2
+f 0 1 2
+g
+f 1 2 3
+");
+    assert_eq!(obj.vertices.len(), 3);
+    assert_eq!(obj.ignored, 5);
+    assert_eq!(obj.default_group.len(), 1);
+}
+
+#[test]
+fn test_obj_out_of_range_indices_are_skipped() {
+    let obj = parse("v 0 1 0
+v -1 0 0
+v 1 0 0
+f -9223372036854775808 1 2
+f 9223372036854775807 1 2
+f -4 1 2
+f 1 2 3
+");
+    assert_eq!(obj.ignored, 3);
+    assert_eq!(obj.default_group.len(), 1);
+}
+
+#[test]
+fn test_obj_degenerate_triangles_are_skipped() {
+    let obj = parse("v 0 0 0
+v 1 0 0
+v 2 0 0
+v 0 1 0
+f 1 2 3 4
+");
+    // the first triangle of the fan has all its corners on the x axis
+    assert_eq!(obj.default_group.len(), 1);
+    let t = triangle(obj.default_group[0].as_ref());
+    assert_eq!(t.p2(), &obj.vertices[2]);
+}
+
+#[test]
+fn test_obj_load_file() -> Result<(), Box<dyn Error>> {
+    let obj = ObjFile::load("scenes/pyramid.obj")?;
+    assert_eq!(obj.vertices.len(), 5);
+    assert_eq!(obj.group("sides").unwrap().len(), 4);
+    assert_eq!(obj.group("base").unwrap().len(), 2);
+    assert!(ObjFile::load("scenes/no_such_file.obj").is_err());
+    Ok(())
+}
@@ -0,0 +1,295 @@
+use book_renderer::{
+    color::{Color, BLACK}, intersectable::Intersectable, matrix4::Matrix4,
+    material::Material,
+    pattern::{BlendedPattern, CheckersPattern, GradientPattern, Paint, Pattern, PatternSpace, PerturbedPattern, RingPattern, StripePattern},
+    sphere::Sphere, tuple::{Point, Tuple},
+};
+
+fn white() -> Color {
+    Color::white(1.0)
+}
+
+// In book: test_pattern()
+// Returns the point it was given as a color, so tests can see where it was looked up
+#[derive(Debug)]
+struct TestPattern {
+    space: PatternSpace,
+}
+
+impl TestPattern {
+    fn new() -> TestPattern {
+        TestPattern { space: PatternSpace::new() }
+    }
+}
+
+impl Pattern for TestPattern {
+    fn space(&self) -> &PatternSpace {
+        &self.space
+    }
+    fn mut_space(&mut self) -> &mut PatternSpace {
+        &mut self.space
+    }
+    fn local_color_at(&self, point: &Point) -> Color {
+        Color::new(point.x, point.y, point.z)
+    }
+}
+
+// pg 128
+#[test]
+fn test_book_stripe_pattern() {
+    let pattern = StripePattern::new(white(), BLACK);
+    assert_eq!(pattern.a, white());
+    assert_eq!(pattern.b, BLACK);
+}
+
+// pg 129
+#[test]
+fn test_book_stripe_constant_in_y() {
+    let pattern = StripePattern::new(white(), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 1.0, 0.0)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 2.0, 0.0)), white());
+}
+
+// pg 129
+#[test]
+fn test_book_stripe_constant_in_z() {
+    let pattern = StripePattern::new(white(), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 0.0, 1.0)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 0.0, 2.0)), white());
+}
+
+// pg 129
+#[test]
+fn test_book_stripe_alternates_in_x() {
+    let pattern = StripePattern::new(white(), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.9, 0.0, 0.0)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(1.0, 0.0, 0.0)), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(-0.1, 0.0, 0.0)), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(-1.0, 0.0, 0.0)), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(-1.1, 0.0, 0.0)), white());
+}
+
+// pg 131
+#[test]
+fn test_book_stripe_object_transform() {
+    let mut object = Sphere::new_unit();
+    object.set_transform(Matrix4::scaling(2.0, 2.0, 2.0)).unwrap();
+    let pattern = StripePattern::new(white(), BLACK);
+    assert_eq!(pattern.color_at_object(&object, &Tuple::new_point(1.5, 0.0, 0.0)), white());
+}
+
+// pg 131
+#[test]
+fn test_book_stripe_pattern_transform() {
+    let object = Sphere::new_unit();
+    let mut pattern = StripePattern::new(white(), BLACK);
+    pattern.set_transform(Matrix4::scaling(2.0, 2.0, 2.0)).unwrap();
+    assert_eq!(pattern.color_at_object(&object, &Tuple::new_point(1.5, 0.0, 0.0)), white());
+}
+
+// pg 131
+#[test]
+fn test_book_stripe_object_and_pattern_transform() {
+    let mut object = Sphere::new_unit();
+    object.set_transform(Matrix4::scaling(2.0, 2.0, 2.0)).unwrap();
+    let mut pattern = StripePattern::new(white(), BLACK);
+    pattern.set_transform(Matrix4::translation(0.5, 0.0, 0.0)).unwrap();
+    assert_eq!(pattern.color_at_object(&object, &Tuple::new_point(2.5, 0.0, 0.0)), white());
+}
+
+// pg 133
+#[test]
+fn test_book_pattern_default_transform() {
+    let pattern = TestPattern::new();
+    assert_eq!(pattern.transformation(), &Matrix4::identity());
+}
+
+// pg 133
+#[test]
+fn test_book_pattern_assign_transform() {
+    let mut pattern = TestPattern::new();
+    pattern.set_transform(Matrix4::translation(1.0, 2.0, 3.0)).unwrap();
+    assert_eq!(pattern.transformation(), &Matrix4::translation(1.0, 2.0, 3.0));
+}
+
+// pg 134
+#[test]
+fn test_book_pattern_with_object_transform() {
+    let mut object = Sphere::new_unit();
+    object.set_transform(Matrix4::scaling(2.0, 2.0, 2.0)).unwrap();
+    let pattern = TestPattern::new();
+    assert_eq!(pattern.color_at_object(&object, &Tuple::new_point(2.0, 3.0, 4.0)), Color::new(1.0, 1.5, 2.0));
+}
+
+// pg 134
+#[test]
+fn test_book_pattern_with_pattern_transform() {
+    let object = Sphere::new_unit();
+    let mut pattern = TestPattern::new();
+    pattern.set_transform(Matrix4::scaling(2.0, 2.0, 2.0)).unwrap();
+    assert_eq!(pattern.color_at_object(&object, &Tuple::new_point(2.0, 3.0, 4.0)), Color::new(1.0, 1.5, 2.0));
+}
+
+// pg 134
+#[test]
+fn test_book_pattern_with_object_and_pattern_transform() {
+    let mut object = Sphere::new_unit();
+    object.set_transform(Matrix4::scaling(2.0, 2.0, 2.0)).unwrap();
+    let mut pattern = TestPattern::new();
+    pattern.set_transform(Matrix4::translation(0.5, 1.0, 1.5)).unwrap();
+    assert_eq!(pattern.color_at_object(&object, &Tuple::new_point(2.5, 3.0, 3.5)), Color::new(0.75, 0.5, 0.25));
+}
+
+#[test]
+fn test_pattern_rejects_singular_transform() {
+    let mut pattern = TestPattern::new();
+    assert!(pattern.set_transform(Matrix4::scaling(0.0, 1.0, 1.0)).is_err());
+    assert_eq!(pattern.transformation(), &Matrix4::identity());
+}
+
+// pg 135
+#[test]
+fn test_book_gradient_interpolates() {
+    let pattern = GradientPattern::new(white(), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.25, 0.0, 0.0)), Color::white(0.75));
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.5, 0.0, 0.0)), Color::white(0.5));
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.75, 0.0, 0.0)), Color::white(0.25));
+}
+
+// pg 136
+#[test]
+fn test_book_ring_extends_in_x_and_z() {
+    let pattern = RingPattern::new(white(), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(1.0, 0.0, 0.0)), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 0.0, 1.0)), BLACK);
+    // 0.708 = just slightly more than sqrt(2)/2
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.708, 0.0, 0.708)), BLACK);
+}
+
+// pg 137
+#[test]
+fn test_book_checkers_repeat_in_x() {
+    let pattern = CheckersPattern::new(white(), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.99, 0.0, 0.0)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(1.01, 0.0, 0.0)), BLACK);
+}
+
+// pg 137
+#[test]
+fn test_book_checkers_repeat_in_y() {
+    let pattern = CheckersPattern::new(white(), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 0.99, 0.0)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 1.01, 0.0)), BLACK);
+}
+
+// pg 137
+#[test]
+fn test_book_checkers_repeat_in_z() {
+    let pattern = CheckersPattern::new(white(), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 0.0, 0.99)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.0, 0.0, 1.01)), BLACK);
+}
+
+#[test]
+fn test_checkers_negative_coordinates() {
+    let pattern = CheckersPattern::new(white(), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(-0.5, 0.0, 0.0)), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(-0.5, 0.0, -0.5)), white());
+}
+
+#[test]
+fn test_nested_checkers_of_stripes() {
+    let red = Color::new(1.0, 0.0, 0.0);
+    let blue = Color::new(0.0, 0.0, 1.0);
+    let pattern = CheckersPattern::new(StripePattern::new(white(), BLACK), StripePattern::new(red, blue));
+    // the first checker is white/black stripes, the next one along z is red/blue stripes
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.5, 0.0, 0.5)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(1.5, 0.0, 1.5)), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.5, 0.0, 1.5)), red);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(1.5, 0.0, 0.5)), blue);
+}
+
+#[test]
+fn test_nested_pattern_uses_its_own_transform() {
+    let mut stripes = StripePattern::new(white(), BLACK);
+    stripes.set_transform(Matrix4::scaling(0.25, 1.0, 1.0)).unwrap();
+    let pattern = CheckersPattern::new(stripes, BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.1, 0.0, 0.0)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.3, 0.0, 0.0)), BLACK);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.6, 0.0, 0.0)), white());
+}
+
+#[test]
+fn test_nested_pattern_inside_transformed_pattern() {
+    let object = Sphere::new_unit();
+    let mut pattern = StripePattern::new(StripePattern::new(white(), BLACK), BLACK);
+    pattern.set_transform(Matrix4::scaling(4.0, 1.0, 1.0)).unwrap();
+    // 2.0 in object space is 0.5 in the outer pattern, which is still 0.5 for the inner stripes
+    assert_eq!(pattern.color_at_object(&object, &Tuple::new_point(2.0, 0.0, 0.0)), white());
+    assert_eq!(pattern.color_at_object(&object, &Tuple::new_point(4.5, 0.0, 0.0)), BLACK);
+}
+
+#[test]
+fn test_paint_from_color_and_pattern() {
+    let solid: Paint = white().into();
+    assert_eq!(solid, white());
+    let nested: Paint = StripePattern::new(white(), BLACK).into();
+    assert_ne!(nested, white());
+    assert_eq!(nested.color_at(&Tuple::new_point(1.5, 0.0, 0.0)), BLACK);
+}
+
+#[test]
+fn test_blended_pattern_averages() {
+    let horizontal = StripePattern::new(white(), BLACK);
+    let mut vertical = StripePattern::new(white(), BLACK);
+    vertical.set_transform(Matrix4::rotation_y(std::f64::consts::PI / 2.0)).unwrap();
+    let pattern = BlendedPattern::new(horizontal, vertical);
+    // the rotated stripes run along x, changing color as z goes from -0.5 to 0.5
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.5, 0.0, -0.5)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(1.5, 0.0, -0.5)), Color::white(0.5));
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.5, 0.0, 0.5)), Color::white(0.5));
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(1.5, 0.0, 0.5)), BLACK);
+}
+
+#[test]
+fn test_blended_colors() {
+    let pattern = BlendedPattern::new(Color::new(1.0, 0.0, 0.0), Color::new(0.0, 0.0, 1.0));
+    assert_eq!(pattern.local_color_at(&Tuple::origin_point()), Color::new(0.5, 0.0, 0.5));
+}
+
+#[test]
+fn test_perturbed_pattern_without_scale_matches_inner() {
+    let pattern = PerturbedPattern::new(StripePattern::new(white(), BLACK), 0.0);
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(0.9, 0.3, 0.2)), white());
+    assert_eq!(pattern.local_color_at(&Tuple::new_point(1.1, 0.3, 0.2)), BLACK);
+}
+
+#[test]
+fn test_perturbed_pattern_moves_stripe_edges() {
+    let pattern = PerturbedPattern::new(GradientPattern::new(BLACK, white()), 0.2);
+    let plain = GradientPattern::new(BLACK, white());
+    let points = (0..20).map(|i| Tuple::new_point(0.3, i as f64 * 0.37, i as f64 * 0.21));
+    let moved = points.clone().filter(|p| pattern.local_color_at(p) != plain.local_color_at(p)).count();
+    assert!(moved > 10);
+    // it's noise, not randomness: the same point always gets the same color
+    for p in points {
+        assert_eq!(pattern.local_color_at(&p), pattern.local_color_at(&p));
+    }
+}
+
+#[test]
+fn test_material_with_nested_pattern() {
+    let mut m = Material::default();
+    m.set_pattern(CheckersPattern::new(StripePattern::new(white(), BLACK), white()));
+    let object = Sphere::new_unit();
+    assert_eq!(m.color_at(&object, &Tuple::new_point(0.5, 0.0, 0.5)), white());
+    assert_eq!(m.color_at(&object, &Tuple::new_point(-0.5, 0.0, -0.5)), BLACK);
+}
@@ -0,0 +1,30 @@
+use book_renderer::{perlin::noise, tuple::Tuple};
+use proptest::prelude::*;
+
+#[test]
+fn test_noise_zero_on_lattice() {
+    assert_eq!(noise(&Tuple::origin_point()), 0.0);
+    assert_eq!(noise(&Tuple::new_point(3.0, -7.0, 12.0)), 0.0);
+}
+
+#[test]
+fn test_noise_varies() {
+    let a = noise(&Tuple::new_point(0.5, 0.5, 0.5));
+    let b = noise(&Tuple::new_point(1.7, 0.2, 3.3));
+    assert_ne!(a, b);
+}
+
+proptest! {
+    #[test]
+    fn noise_in_range(x in -1000.0..1000.0f64, y in -1000.0..1000.0f64, z in -1000.0..1000.0f64) {
+        let n = noise(&Tuple::new_point(x, y, z));
+        prop_assert!((-1.0..=1.0).contains(&n));
+    }
+
+    #[test]
+    fn noise_is_smooth(x in -100.0..100.0f64, y in -100.0..100.0f64, z in -100.0..100.0f64) {
+        let n1 = noise(&Tuple::new_point(x, y, z));
+        let n2 = noise(&Tuple::new_point(x + 0.001, y, z));
+        prop_assert!((n1 - n2).abs() < 0.01);
+    }
+}
@@ -0,0 +1,77 @@
+use std::f64::consts::PI;
+
+use book_renderer::{plane::Plane, sphere::Sphere, matrix4::Matrix4, ray::Ray, tuple::Tuple, light::Light, color::Color, world::World, intersectable::Intersectable};
+
+// pg 122
+#[test]
+fn test_book_plane_normal_constant() {
+    let p = Plane::new();
+    let expected = Tuple::new_vector(0.0, 1.0, 0.0);
+    assert_eq!(p.surface_normal(&Tuple::new_point(0.0, 0.0, 0.0)), expected);
+    assert_eq!(p.surface_normal(&Tuple::new_point(10.0, 0.0, -10.0)), expected);
+    assert_eq!(p.surface_normal(&Tuple::new_point(-5.0, 0.0, 150.0)), expected);
+}
+
+// pg 123
+#[test]
+fn test_book_plane_intersect_parallel() {
+    let p = Plane::new();
+    let r = Ray::new(Tuple::new_point(0.0, 10.0, 0.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert!(p.intersections(&r).is_empty());
+}
+
+// pg 123
+#[test]
+fn test_book_plane_intersect_coplanar() {
+    let p = Plane::new();
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, 0.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert!(p.intersections(&r).is_empty());
+}
+
+// pg 123
+#[test]
+fn test_book_plane_intersect_from_above() {
+    let p = Plane::new();
+    let r = Ray::new(Tuple::new_point(0.0, 1.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0));
+    let xs = p.intersections(&r);
+    assert_eq!(xs.len(), 1);
+    assert_eq!(xs[0].t, 1.0);
+    assert_eq!(xs[0].object, &p);
+}
+
+// pg 123
+#[test]
+fn test_book_plane_intersect_from_below() {
+    let p = Plane::new();
+    let r = Ray::new(Tuple::new_point(0.0, -1.0, 0.0), Tuple::new_vector(0.0, 1.0, 0.0));
+    let xs = p.intersections(&r);
+    assert_eq!(xs.len(), 1);
+    assert_eq!(xs[0].t, 1.0);
+    assert_eq!(xs[0].object, &p);
+}
+
+#[test]
+fn test_plane_transformed_normal() {
+    let mut p = Plane::new();
+    p.set_transform(Matrix4::rotation_z(PI / 2.0)).unwrap();
+    assert_eq!(p.surface_normal(&Tuple::new_point(0.0, 5.0, 0.0)), Tuple::new_vector(-1.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_plane_in_world_with_spheres() {
+    let mut floor = Plane::new();
+    floor.set_transform(Matrix4::translation(0.0, -1.0, 0.0)).unwrap();
+    let w = World::new(
+        vec![Light::new_point(Tuple::new_point(0.0, 10.0, 0.0), Color::white(1.0))],
+        vec![Box::new(floor), Box::new(Sphere::new_unit())],
+    );
+    // straight down through the sphere: it hits the sphere first and the floor after
+    let r = Ray::new(Tuple::new_point(0.0, 5.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0));
+    let xs = w.intersections(&r);
+    assert_eq!(xs.len(), 3);
+    assert_eq!(xs[2].t, 6.0);
+    // the floor next to the sphere is lit, the floor underneath it is in shadow
+    let beside = Ray::new(Tuple::new_point(3.0, 5.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0));
+    assert!(w.color_at(&beside).red > 0.1);
+    assert!(w.is_shadowed(w.light().unwrap(), &Tuple::new_point(0.0, -0.99, 0.0)));
+}
@@ -0,0 +1,132 @@
+use book_renderer::{polynomial::{real_roots, solve_quadratic, solve_quartic, evaluate, derivative}, util::Float};
+use proptest::prelude::*;
+
+mod common;
+use common::assert_ts as assert_roots;
+
+// (x - r1)(x - r2)... multiplied out, highest power first
+fn from_roots(roots: &[Float]) -> Vec<Float> {
+    let mut coefficients = vec![1.0];
+    for r in roots {
+        let mut next = coefficients.clone();
+        next.push(0.0);
+        for (i, c) in coefficients.iter().enumerate() {
+            next[i + 1] -= r * c;
+        }
+        coefficients = next;
+    }
+    coefficients
+}
+
+#[test]
+fn test_evaluate_and_derivative() {
+    // 2x³ - 3x + 1
+    let p = [2.0, 0.0, -3.0, 1.0];
+    assert_eq!(evaluate(&p, 2.0), 11.0);
+    assert_eq!(derivative(&p), vec![6.0, 0.0, -3.0]);
+    assert!(derivative(&[5.0]).is_empty());
+}
+
+#[test]
+fn test_from_roots() {
+    assert_eq!(from_roots(&[1.0, 2.0]), vec![1.0, -3.0, 2.0]);
+}
+
+#[test]
+fn test_linear_and_constant() {
+    assert_roots(real_roots(&[2.0, -3.0]), &[1.5]);
+    assert_roots(real_roots(&[0.0, 0.0, 2.0, -3.0]), &[1.5]);
+    assert_roots(real_roots(&[4.0]), &[]);
+    assert_roots(real_roots(&[]), &[]);
+}
+
+#[test]
+fn test_quadratic() {
+    assert_roots(solve_quadratic(1.0, -3.0, 2.0), &[2.0, 1.0]);
+    assert_roots(real_roots(&[1.0, -3.0, 2.0]), &[1.0, 2.0]);
+    assert_roots(solve_quadratic(1.0, 0.0, 1.0), &[]);
+    assert_roots(solve_quadratic(1.0, -2.0, 1.0), &[1.0, 1.0]);
+    assert_roots(solve_quadratic(0.0, 2.0, -1.0), &[0.5]);
+}
+
+#[test]
+fn test_quadratic_keeps_small_root() {
+    // the textbook formula gets 0 for the small root here
+    let roots = real_roots(&[1.0, -1e9, 1.0]);
+    assert!((roots[0] - 1e-9).abs() < 1e-20);
+}
+
+#[test]
+fn test_cubic() {
+    assert_roots(real_roots(&from_roots(&[-1.0, 0.5, 3.0])), &[-1.0, 0.5, 3.0]);
+    // x³ + x + 1 has one real root
+    assert_roots(real_roots(&[1.0, 0.0, 1.0, 1.0]), &[-0.6823278]);
+}
+
+#[test]
+fn test_quartic_distinct_roots() {
+    assert_roots(real_roots(&from_roots(&[1.0, 2.0, 3.0, 4.0])), &[1.0, 2.0, 3.0, 4.0]);
+    let p = from_roots(&[-6.25, -3.75, 3.75, 6.25]);
+    assert_roots(solve_quartic(p[0], p[1], p[2], p[3], p[4]), &[-6.25, -3.75, 3.75, 6.25]);
+}
+
+#[test]
+fn test_quartic_no_real_roots() {
+    assert_roots(solve_quartic(1.0, 0.0, 0.0, 0.0, 1.0), &[]);
+    // (x² + 1)(x² + 4)
+    assert_roots(solve_quartic(1.0, 0.0, 5.0, 0.0, 4.0), &[]);
+}
+
+#[test]
+fn test_quartic_two_real_roots() {
+    // (x - 1)(x + 2)(x² + 1)
+    assert_roots(real_roots(&[1.0, 1.0, -1.0, 1.0, -2.0]), &[-2.0, 1.0]);
+}
+
+#[test]
+fn test_quartic_touching_roots_count_twice() {
+    // (x - 1)²(x - 3)²: touches zero twice without crossing, like a ray grazing a torus
+    assert_roots(real_roots(&from_roots(&[1.0, 1.0, 3.0, 3.0])), &[1.0, 1.0, 3.0, 3.0]);
+    // (x - 2)² (x + 1)(x - 5): one touching root between two crossings
+    assert_roots(real_roots(&from_roots(&[2.0, 2.0, -1.0, 5.0])), &[-1.0, 2.0, 2.0, 5.0]);
+    // x⁴ only touches at 0
+    assert_roots(real_roots(&[1.0, 0.0, 0.0, 0.0, 0.0]), &[0.0, 0.0]);
+}
+
+#[test]
+fn test_quartic_close_roots() {
+    let roots = real_roots(&from_roots(&[1.0, 1.001, 5.0, 5.0001]));
+    assert_eq!(roots.len(), 4);
+    for (a, e) in roots.iter().zip([1.0, 1.001, 5.0, 5.0001]) {
+        assert!((a - e).abs() < 1e-7, "{:?}", roots);
+    }
+}
+
+#[test]
+fn test_quartic_triple_root_crosses_once() {
+    assert_roots(real_roots(&from_roots(&[2.0, 2.0, 2.0, -1.0])), &[-1.0, 2.0]);
+}
+
+proptest! {
+    #[test]
+    fn quartic_finds_every_root(mut roots in prop::array::uniform4(-20.0..20.0f64)) {
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        // roots on top of each other are covered above
+        prop_assume!(roots.windows(2).all(|w| w[1] - w[0] > 0.01));
+        let found = real_roots(&from_roots(&roots));
+        prop_assert_eq!(found.len(), 4);
+        for (a, e) in found.iter().zip(roots) {
+            prop_assert!((a - e).abs() < 1e-6, "{:?} is not {:?}", found, roots);
+        }
+    }
+
+    #[test]
+    fn roots_are_roots(coefficients in prop::array::uniform5(-100.0..100.0f64)) {
+        prop_assume!(coefficients[0].abs() > 0.01);
+        for x in real_roots(&coefficients) {
+            // relative to the size of the terms being added up
+            let scale: Float = coefficients.iter().fold(0.0, |total, c| total * x.abs() + c.abs());
+            prop_assert!(evaluate(&coefficients, x).abs() <= scale * 1e-9);
+        }
+    }
+}
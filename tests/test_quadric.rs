@@ -0,0 +1,178 @@
+use book_renderer::{
+    quadric::Quadric, sphere::Sphere, cylinder::Cylinder, bounds::BoundingBox, ray::Ray, matrix4::Matrix4,
+    tuple::Tuple, util::Float, intersectable::Intersectable,
+};
+
+mod common;
+use common::{ray, ts, assert_ts};
+
+fn bounding_box(min: (Float, Float, Float), max: (Float, Float, Float)) -> BoundingBox {
+    BoundingBox::new(Tuple::new_point(min.0, min.1, min.2), Tuple::new_point(max.0, max.1, max.2))
+}
+
+#[test]
+fn test_unit_sphere_quadric_matches_sphere() {
+    let q = Quadric::ellipsoid(1.0, 1.0, 1.0);
+    let s = Sphere::new_unit();
+    let rays = [
+        ray(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0)),
+        ray(Tuple::new_point(0.3, -0.2, -5.0), Tuple::new_vector(0.1, 0.2, 1.0)),
+        ray(Tuple::origin_point(), Tuple::new_vector(1.0, 1.0, 0.0)),
+        ray(Tuple::new_point(0.0, 2.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0)),
+    ];
+    for r in rays {
+        assert_ts(ts(&q, &r), &ts(&s, &r));
+    }
+    let p = Tuple::new_point(0.0, 0.6, 0.8);
+    assert_eq!(q.surface_normal(&p), s.surface_normal(&p));
+}
+
+#[test]
+fn test_ellipsoid() {
+    let q = Quadric::ellipsoid(3.0, 2.0, 1.0);
+    let r = ray(Tuple::new_point(-5.0, 0.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert_ts(ts(&q, &r), &[2.0, 8.0]);
+    let r = ray(Tuple::new_point(0.0, 5.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0));
+    assert_ts(ts(&q, &r), &[3.0, 7.0]);
+    assert_eq!(q.surface_normal(&Tuple::new_point(3.0, 0.0, 0.0)), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert_eq!(q.bounds(), bounding_box((-3.0, -2.0, -1.0), (3.0, 2.0, 1.0)));
+}
+
+#[test]
+fn test_paraboloid() {
+    let q = Quadric::paraboloid();
+    // straight down the axis there's only the bottom of the bowl
+    let r = ray(Tuple::new_point(0.0, 5.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0));
+    assert_ts(ts(&q, &r), &[5.0]);
+    // across the bowl at y = 4 it's 2 from the axis
+    let r = ray(Tuple::new_point(-5.0, 4.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert_ts(ts(&q, &r), &[3.0, 7.0]);
+    let r = ray(Tuple::new_point(-5.0, -1.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert!(q.local_intersect(&r).is_empty());
+    assert_eq!(q.surface_normal(&Tuple::origin_point()), Tuple::new_vector(0.0, -1.0, 0.0));
+    let n = q.surface_normal(&Tuple::new_point(1.0, 1.0, 0.0));
+    assert_eq!(n, Tuple::new_vector(2.0, -1.0, 0.0).normalize().unwrap());
+}
+
+#[test]
+fn test_hyperboloid_one_sheet() {
+    let q = Quadric::hyperboloid_one_sheet();
+    let r = ray(Tuple::new_point(-5.0, 0.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert_ts(ts(&q, &r), &[4.0, 6.0]);
+    // wider higher up: x² = y² + 1
+    let r = ray(Tuple::new_point(-5.0, 3.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert_ts(ts(&q, &r), &[5.0 - 10.0_f64.sqrt(), 5.0 + 10.0_f64.sqrt()]);
+    // straight up the middle never touches it
+    let r = ray(Tuple::new_point(0.0, -5.0, 0.0), Tuple::new_vector(0.0, 1.0, 0.0));
+    assert!(q.local_intersect(&r).is_empty());
+    assert_eq!(q.surface_normal(&Tuple::new_point(0.0, 0.0, 1.0)), Tuple::new_vector(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_hyperboloid_two_sheets() {
+    let q = Quadric::hyperboloid_two_sheets();
+    let r = ray(Tuple::new_point(0.0, -5.0, 0.0), Tuple::new_vector(0.0, 1.0, 0.0));
+    assert_ts(ts(&q, &r), &[4.0, 6.0]);
+    // between the sheets
+    let r = ray(Tuple::new_point(-5.0, 0.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert!(q.local_intersect(&r).is_empty());
+    // the sheets face each other
+    assert_eq!(q.surface_normal(&Tuple::new_point(0.0, 1.0, 0.0)), Tuple::new_vector(0.0, -1.0, 0.0));
+    assert_eq!(q.surface_normal(&Tuple::new_point(0.0, -1.0, 0.0)), Tuple::new_vector(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_elliptic_cylinder() {
+    let q = Quadric::elliptic_cylinder(2.0, 1.0);
+    let r = ray(Tuple::new_point(-5.0, 7.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert_ts(ts(&q, &r), &[3.0, 7.0]);
+    let r = ray(Tuple::new_point(0.0, -7.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_ts(ts(&q, &r), &[4.0, 6.0]);
+    assert_eq!(q.surface_normal(&Tuple::new_point(0.0, 3.0, 1.0)), Tuple::new_vector(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_truncated_quadric_matches_cylinder() {
+    // the book's capped cylinder tests (pg 185), on a quadric cylinder
+    let q = Quadric::elliptic_cylinder(1.0, 1.0).truncated(1.0, 2.0, true);
+    let c = Cylinder::new_truncated(1.0, 2.0, true);
+    let cases = [
+        (Tuple::new_point(0.0, 3.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0), 2),
+        (Tuple::new_point(0.0, 3.0, -2.0), Tuple::new_vector(0.0, -1.0, 2.0), 2),
+        (Tuple::new_point(0.0, 4.0, -2.0), Tuple::new_vector(0.0, -1.0, 1.0), 2),
+        (Tuple::new_point(0.0, 0.0, -2.0), Tuple::new_vector(0.0, 1.0, 2.0), 2),
+        (Tuple::new_point(0.0, -1.0, -2.0), Tuple::new_vector(0.0, 1.0, 1.0), 2),
+        (Tuple::new_point(0.0, 1.5, -2.0), Tuple::new_vector(0.1, 1.0, 0.0), 0),
+    ];
+    for (origin, direction, count) in cases {
+        let r = ray(origin, direction);
+        let mut expected = ts(&c, &r);
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut actual = ts(&q, &r);
+        actual.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(actual.len(), count);
+        assert_ts(actual, &expected);
+    }
+    for p in [Tuple::new_point(0.5, 1.0, 0.0), Tuple::new_point(0.0, 2.0, 0.5), Tuple::new_point(1.0, 1.5, 0.0)] {
+        assert_eq!(q.surface_normal(&p), c.surface_normal(&p));
+    }
+}
+
+#[test]
+fn test_capped_paraboloid_is_a_cup() {
+    let q = Quadric::paraboloid().truncated(Float::NEG_INFINITY, 1.0, true);
+    // in through the lid, out through the bottom
+    let r = ray(Tuple::new_point(0.5, 5.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0));
+    assert_ts(ts(&q, &r), &[4.75, 4.0]);
+    assert_eq!(q.surface_normal(&Tuple::new_point(0.5, 1.0, 0.0)), Tuple::new_vector(0.0, 1.0, 0.0));
+    // beside the cup
+    let r = ray(Tuple::new_point(1.5, 5.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0));
+    assert!(q.local_intersect(&r).is_empty());
+}
+
+#[test]
+fn test_general_coefficients() {
+    // a unit sphere moved to (1, 2, 3): x² + y² + z² - 2x - 4y - 6z + 13 = 0
+    let q = Quadric::new([1.0, 1.0, 1.0, 0.0, 0.0, 0.0, -2.0, -4.0, -6.0, 13.0]);
+    let mut s = Sphere::new_unit();
+    s.set_transform(Matrix4::translation(1.0, 2.0, 3.0)).unwrap();
+    let r = ray(Tuple::new_point(1.2, 2.1, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_ts(ts(&q, &r), &s.intersections(&r).iter().map(|x| x.t).collect::<Vec<Float>>());
+    let p = Tuple::new_point(1.0, 2.0, 2.0);
+    assert_eq!(q.surface_normal(&p), s.surface_normal(&p));
+    // cross terms: a cylinder around the line x = z, x²/2 - xz + z²/2 + y² = 1
+    let q = Quadric::new([0.5, 1.0, 0.5, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, -1.0]);
+    let r = ray(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_ts(ts(&q, &r), &[5.0 - 2.0_f64.sqrt(), 5.0 + 2.0_f64.sqrt()]);
+}
+
+#[test]
+fn test_transformed_quadric() {
+    let mut q = Quadric::paraboloid();
+    q.set_transform(Matrix4::translation(0.0, -2.0, 0.0)).unwrap();
+    let r = Ray::new(Tuple::new_point(0.0, 5.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0));
+    assert_eq!(q.intersections(&r).iter().map(|x| x.t).collect::<Vec<Float>>(), vec![7.0]);
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_quadric_bounds() {
+    let q = Quadric::paraboloid().truncated(0.0, 4.0, false);
+    assert_eq!(q.bounds(), bounding_box((-2.0, 0.0, -2.0), (2.0, 4.0, 2.0)));
+    let q = Quadric::hyperboloid_one_sheet().truncated(-3.0, 1.0, true);
+    let wide = 10.0_f64.sqrt();
+    assert_eq!(q.bounds(), bounding_box((-wide, -3.0, -wide), (wide, 1.0, wide)));
+    let q = Quadric::elliptic_cylinder(2.0, 0.5);
+    let b = q.bounds();
+    assert_eq!((b.min.x, b.max.x, b.min.z, b.max.z), (-2.0, 2.0, -0.5, 0.5));
+    assert_eq!((b.min.y, b.max.y), (Float::NEG_INFINITY, Float::INFINITY));
+    // a slice of an ellipsoid
+    let q = Quadric::ellipsoid(2.0, 2.0, 2.0).truncated(1.0, 5.0, false);
+    let r = 3.0_f64.sqrt();
+    assert_eq!(q.bounds(), bounding_box((-r, 1.0, -r), (r, 2.0, r)));
+    // anything open without ellipses as slices only has y bounds
+    let b = Quadric::hyperboloid_two_sheets().bounds();
+    assert_eq!((b.min.x, b.max.x), (Float::NEG_INFINITY, Float::INFINITY));
+    let b = Quadric::new([0.5, 1.0, 0.5, 0.0, -1.0, 0.0, 0.0, 0.0, 0.0, -1.0]).truncated(-1.0, 1.0, false).bounds();
+    assert_eq!((b.min.x, b.max.x, b.min.y, b.max.y), (Float::NEG_INFINITY, Float::INFINITY, -1.0, 1.0));
+}
@@ -1,5 +1,5 @@
 
-use book_renderer::{tuple::Tuple, ray::Ray, sphere::Sphere, matrix::Matrix, intersectable::Intersectable, intersection::Intersection};
+use book_renderer::{tuple::Tuple, ray::Ray, sphere::Sphere, plane::Plane, matrix4::Matrix4, intersectable::Intersectable, intersection::Intersection, util::{EPSILON, Float, feq}};
 
 #[test]
 fn test_book_new_ray() {
@@ -87,8 +87,8 @@ fn test_book_intersection() {
 #[test]
 fn test_book_intersection_hits_all_positive() {
     let s = Sphere::new_unit();
-    let i1 = Intersection{ t: 1.0, object: &s };
-    let i2 = Intersection{ t: 2.0, object: &s };
+    let i1 = Intersection::new(1.0, &s);
+    let i2 = Intersection::new(2.0, &s);
     let expect = Some(i1.clone());
     let xs = vec![i2, i1];
     let i = Intersection::hit(&xs);
@@ -98,8 +98,8 @@ fn test_book_intersection_hits_all_positive() {
 #[test]
 fn test_book_intersection_hits_mixed_positive() {
     let s = Sphere::new_unit();
-    let i1 = Intersection{ t: -1.0, object: &s };
-    let i2 = Intersection{ t: 1.0, object: &s };
+    let i1 = Intersection::new(-1.0, &s);
+    let i2 = Intersection::new(1.0, &s);
     let expect = Some(i2.clone());
     let xs = vec![i2, i1];
     let i = Intersection::hit(&xs);
@@ -109,8 +109,8 @@ fn test_book_intersection_hits_mixed_positive() {
 #[test]
 fn test_book_intersection_hits_all_negative() {
     let s = Sphere::new_unit();
-    let i1 = Intersection{ t: -2.0, object: &s };
-    let i2 = Intersection{ t: -1.0, object: &s };
+    let i1 = Intersection::new(-2.0, &s);
+    let i2 = Intersection::new(-1.0, &s);
     let xs = vec![i2, i1];
     let i = Intersection::hit(&xs);
     assert_eq!(i, None);
@@ -119,10 +119,10 @@ fn test_book_intersection_hits_all_negative() {
 #[test]
 fn test_book_intersection_is_lowest() {
     let s = Sphere::new_unit();
-    let i1 = Intersection{ t: 5.0, object: &s };
-    let i2 = Intersection{ t: 7.0, object: &s };
-    let i3 = Intersection{ t: -3.0, object: &s };
-    let i4 = Intersection{ t: 2.0, object: &s };
+    let i1 = Intersection::new(5.0, &s);
+    let i2 = Intersection::new(7.0, &s);
+    let i3 = Intersection::new(-3.0, &s);
+    let i4 = Intersection::new(2.0, &s);
     let expect = Some(i4.clone());
     let xs = vec![i1, i2, i3, i4];
     let i = Intersection::hit(&xs);
@@ -132,7 +132,7 @@ fn test_book_intersection_is_lowest() {
 #[test]
 fn test_book_ray_translation() {
     let r = Ray::new(Tuple::new_point(1.0, 2.0, 3.0), Tuple::new_vector(0.0, 1.0, 0.0));
-    let transform = Matrix::translation(3.0, 4.0, 5.0);
+    let transform = Matrix4::translation(3.0, 4.0, 5.0);
     let r2 = transform * r;
     assert_eq!(r2.origin, Tuple::new_point(4.0, 6.0, 8.0));
     assert_eq!(r2.direction, Tuple::new_vector(0.0, 1.0, 0.0));
@@ -140,7 +140,7 @@ fn test_book_ray_translation() {
 #[test]
 fn test_book_ray_scaling() {
     let r = Ray::new(Tuple::new_point(1.0, 2.0, 3.0), Tuple::new_vector(0.0, 1.0, 0.0));
-    let transform = Matrix::scaling(2.0, 3.0, 4.0);
+    let transform = Matrix4::scaling(2.0, 3.0, 4.0);
     let r2 = transform * r;
     assert_eq!(r2.origin, Tuple::new_point(2.0, 6.0, 12.0));
     assert_eq!(r2.direction, Tuple::new_vector(0.0, 3.0, 0.0));
@@ -150,7 +150,7 @@ fn test_book_ray_scaling() {
 fn test_book_intersection_precompute() {
     let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
     let shape = Sphere::new_unit();
-    let i = Intersection{ t: 4.0, object: &shape };
+    let i = Intersection::new(4.0, &shape);
     let comps = i.precompute(&r);
     assert_eq!(comps.object, &shape);
     assert_eq!(comps.point, Tuple::new_point(0.0, 0.0, -1.0));
@@ -163,11 +163,105 @@ fn test_book_intersection_precompute() {
 fn test_book_intersection_precompute_inside() {
     let r = Ray::new(Tuple::new_point(0.0, 0.0, 0.0), Tuple::new_vector(0.0, 0.0, 1.0));
     let shape = Sphere::new_unit();
-    let i = Intersection{ t: 1.0, object: &shape };
+    let i = Intersection::new(1.0, &shape);
     let comps = i.precompute(&r);
     assert_eq!(comps.object, &shape);
     assert_eq!(comps.point, Tuple::new_point(0.0, 0.0, 1.0));
     assert_eq!(comps.eyev, Tuple::new_vector(0.0, 0.0, -1.0));
     assert_eq!(comps.normalv, Tuple::new_vector(0.0, 0.0, -1.0));
     assert_eq!(comps.inside, true);
-}
\ No newline at end of file
+}
+// pg 115
+#[test]
+fn test_book_hit_offsets_point() {
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let mut shape = Sphere::new_unit();
+    shape.set_transform(Matrix4::translation(0.0, 0.0, 1.0)).unwrap();
+    let i = Intersection::new(5.0, &shape);
+    let comps = i.precompute(&r);
+    assert!(comps.over_point.z < -EPSILON/2.0);
+    assert!(comps.point.z > comps.over_point.z);
+}
+
+// pg 143
+#[test]
+fn test_book_precompute_reflection_vector() {
+    let shape = Plane::new();
+    let half = (2.0 as Float).sqrt() / 2.0;
+    let r = Ray::new(Tuple::new_point(0.0, 1.0, -1.0), Tuple::new_vector(0.0, -half, half));
+    let i = Intersection::new((2.0 as Float).sqrt(), &shape);
+    let comps = i.precompute(&r);
+    assert_eq!(comps.reflectv, Tuple::new_vector(0.0, half, half));
+}
+
+// pg 152
+#[test]
+fn test_book_precompute_refractive_indices() {
+    let mut a = Sphere::glass();
+    a.set_transform(Matrix4::scaling(2.0, 2.0, 2.0)).unwrap();
+    a.mut_material().refractive_index = 1.5;
+    let mut b = Sphere::glass();
+    b.set_transform(Matrix4::translation(0.0, 0.0, -0.25)).unwrap();
+    b.mut_material().refractive_index = 2.0;
+    let mut c = Sphere::glass();
+    c.set_transform(Matrix4::translation(0.0, 0.0, 0.25)).unwrap();
+    c.mut_material().refractive_index = 2.5;
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -4.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let xs = vec![
+        Intersection::new(2.0, &a),
+        Intersection::new(2.75, &b),
+        Intersection::new(3.25, &c),
+        Intersection::new(4.75, &b),
+        Intersection::new(5.25, &c),
+        Intersection::new(6.0, &a),
+    ];
+    let expected = [(1.0, 1.5), (1.5, 2.0), (2.0, 2.5), (2.5, 2.5), (2.5, 1.5), (1.5, 1.0)];
+    for (i, (n1, n2)) in xs.iter().zip(expected) {
+        let comps = i.precompute_with_intersections(&r, &xs);
+        assert_eq!(comps.n1, n1);
+        assert_eq!(comps.n2, n2);
+    }
+}
+
+// pg 154
+#[test]
+fn test_book_hit_offsets_under_point() {
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let mut shape = Sphere::glass();
+    shape.set_transform(Matrix4::translation(0.0, 0.0, 1.0)).unwrap();
+    let i = Intersection::new(5.0, &shape);
+    let comps = i.precompute(&r);
+    assert!(comps.under_point.z > EPSILON/2.0);
+    assert!(comps.point.z < comps.under_point.z);
+}
+
+// pg 161
+#[test]
+fn test_book_schlick_total_internal_reflection() {
+    let shape = Sphere::glass();
+    let half = (2.0 as Float).sqrt() / 2.0;
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, half), Tuple::new_vector(0.0, 1.0, 0.0));
+    let xs = vec![Intersection::new(-half, &shape), Intersection::new(half, &shape)];
+    let comps = xs[1].precompute_with_intersections(&r, &xs);
+    assert_eq!(comps.schlick(), 1.0);
+}
+
+// pg 162
+#[test]
+fn test_book_schlick_perpendicular() {
+    let shape = Sphere::glass();
+    let r = Ray::new(Tuple::origin_point(), Tuple::new_vector(0.0, 1.0, 0.0));
+    let xs = vec![Intersection::new(-1.0, &shape), Intersection::new(1.0, &shape)];
+    let comps = xs[1].precompute_with_intersections(&r, &xs);
+    assert!(feq(&comps.schlick(), &0.04));
+}
+
+// pg 163
+#[test]
+fn test_book_schlick_small_angle() {
+    let shape = Sphere::glass();
+    let r = Ray::new(Tuple::new_point(0.0, 0.99, -2.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let xs = vec![Intersection::new(1.8589, &shape)];
+    let comps = xs[0].precompute_with_intersections(&r, &xs);
+    assert!(feq(&comps.schlick(), &0.48873));
+}
@@ -0,0 +1,262 @@
+use std::{error::Error, str::FromStr};
+
+use book_renderer::{scene::{Scene, SceneError}, color::Color, matrix4::Matrix4, tuple::Tuple, ray::Ray, cylinder::Cylinder, cone::Cone, torus::Torus, quadric::Quadric, triangle::Triangle, group::Group, csg::{Csg, CsgOperation}, sphere::Sphere, intersectable::Intersectable, util::Float};
+
+const CAMERA: &str = "camera width=20 height=10 fov=1.0472 from=0,1.5,-5 to=0,1,0 up=0,1,0\n";
+
+fn error_for(contents: &str) -> SceneError {
+    Scene::from_str(contents).unwrap_err()
+}
+
+#[test]
+fn test_load_example_scene() -> Result<(), Box<dyn Error>> {
+    let scene = Scene::load("scenes/three_spheres.scene")?;
+    assert_eq!(scene.camera.hsize(), 200);
+    assert_eq!(scene.camera.vsize(), 100);
+    assert_eq!(scene.world.lights.len(), 1);
+    assert_eq!(scene.world.objects.len(), 4);
+    assert_eq!(scene.world.objects[0].material().specular, 0.0);
+    assert_eq!(scene.world.objects[1].material().color, Color::new(0.1, 1.0, 0.5));
+    Ok(())
+}
+
+#[test]
+fn test_scene_named_material_with_override() -> Result<(), Box<dyn Error>> {
+    let scene = Scene::from_str(&format!("{}
+        light position=0,10,0
+        material name=red color=1,0,0 diffuse=0.2
+        sphere material=red diffuse=0.9 # comments are ignored
+    ", CAMERA))?;
+    let material = scene.world.objects[0].material();
+    assert_eq!(material.color, Color::new(1.0, 0.0, 0.0));
+    assert_eq!(material.diffuse, 0.9);
+    assert_eq!(scene.world.lights[0].intensity, Color::white(1.0));
+    Ok(())
+}
+
+#[test]
+fn test_scene_transforms_apply_in_order() -> Result<(), Box<dyn Error>> {
+    let scene = Scene::from_str(&format!("{}sphere scale=2,2,2 translate=1,0,0\n", CAMERA))?;
+    let expected = Matrix4::translation(1.0, 0.0, 0.0) * Matrix4::scaling(2.0, 2.0, 2.0);
+    assert_eq!(scene.world.objects[0].transformation(), &expected);
+    assert_eq!(scene.world.objects[0].transformation() * Tuple::origin_point(), Tuple::new_point(1.0, 0.0, 0.0));
+    Ok(())
+}
+
+#[test]
+fn test_scene_error_unknown_key() {
+    let e = error_for(&format!("{}\nsphere colour=1,0,0\n", CAMERA));
+    assert_eq!(e.line, Some(3));
+    assert!(e.message.contains("colour"));
+}
+
+#[test]
+fn test_scene_error_bad_number() {
+    let e = error_for(&format!("{}light position=0,ten,0\n", CAMERA));
+    assert_eq!(e.line, Some(2));
+    assert!(e.message.contains("ten"));
+}
+
+#[test]
+fn test_scene_error_undefined_material() {
+    let e = error_for(&format!("{}sphere material=glass\n", CAMERA));
+    assert_eq!(e.line, Some(2));
+    assert!(e.message.contains("glass"));
+}
+
+#[test]
+fn test_scene_error_singular_transform() {
+    let e = error_for(&format!("{}plane scale=0,1,1\n", CAMERA));
+    assert_eq!(e.line, Some(2));
+}
+
+#[test]
+fn test_scene_error_missing_camera() {
+    let e = error_for("light position=0,10,0\n");
+    assert_eq!(e.line, None);
+}
+
+#[test]
+fn test_scene_error_unknown_directive() {
+    let e = error_for(&format!("{}teapot\n", CAMERA));
+    assert_eq!(e.line, Some(2));
+    assert_eq!(e.to_string(), "line 2: unknown directive `teapot`");
+}
+
+#[test]
+fn test_scene_cube() -> Result<(), Box<dyn Error>> {
+    let scene = Scene::from_str(&format!("{}cube scale=1,0.1,1 color=0.5,0.25,0\n", CAMERA))?;
+    assert_eq!(scene.world.objects[0].transformation(), &Matrix4::scaling(1.0, 0.1, 1.0));
+    assert_eq!(scene.world.objects[0].material().color, Color::new(0.5, 0.25, 0.0));
+    Ok(())
+}
+
+#[test]
+fn test_scene_cylinder_and_cone() -> Result<(), Box<dyn Error>> {
+    let scene = Scene::from_str(&format!("{}
+        cylinder minimum=0 maximum=2 closed=true scale=0.5,1,0.5
+        cone maximum=0
+    ", CAMERA))?;
+    let cylinder = scene.world.objects[0].as_any().downcast_ref::<Cylinder>().unwrap();
+    assert_eq!((cylinder.minimum, cylinder.maximum, cylinder.closed), (0.0, 2.0, true));
+    assert_eq!(scene.world.objects[0].transformation(), &Matrix4::scaling(0.5, 1.0, 0.5));
+    let cone = scene.world.objects[1].as_any().downcast_ref::<Cone>().unwrap();
+    assert_eq!((cone.minimum, cone.maximum, cone.closed), (Float::NEG_INFINITY, 0.0, false));
+    Ok(())
+}
+
+#[test]
+fn test_scene_error_bad_closed() {
+    let e = error_for(&format!("{}cylinder closed=yes\n", CAMERA));
+    assert_eq!(e.line, Some(2));
+    assert!(e.message.contains("yes"));
+}
+
+#[test]
+fn test_scene_triangle() -> Result<(), Box<dyn Error>> {
+    let scene = Scene::from_str(&format!("{}triangle p1=0,1,0 p2=-1,0,0 p3=1,0,0 color=1,0,0\n", CAMERA))?;
+    let triangle = scene.world.objects[0].as_any().downcast_ref::<Triangle>().unwrap();
+    assert_eq!(triangle.p2(), &Tuple::new_point(-1.0, 0.0, 0.0));
+    assert_eq!(error_for(&format!("{}triangle p1=0,1,0 p2=-1,0,0\n", CAMERA)).line, Some(2));
+    Ok(())
+}
+
+#[test]
+fn test_scene_error_flat_triangle() {
+    let e = error_for(&format!("{}triangle p1=0,0,0 p2=1,1,1 p3=2,2,2\n", CAMERA));
+    assert_eq!(e.line, Some(2));
+    assert_eq!(e.message, "a triangle's corners can't all be in a line");
+    let e = error_for(&format!("{}triangle p1=0,1,0 p2=0,1,0 p3=1,0,0\n", CAMERA));
+    assert_eq!(e.message, "a triangle's corners can't all be in a line");
+}
+
+#[test]
+fn test_scene_obj_relative_to_scene_file() -> Result<(), Box<dyn Error>> {
+    let scene = Scene::load("scenes/pyramid.scene")?;
+    // the floor, then the pyramid as one group holding its `sides` and `base` groups
+    assert_eq!(scene.world.objects.len(), 2);
+    let pyramid = scene.world.objects[1].as_any().downcast_ref::<Group>().unwrap();
+    assert_eq!(pyramid.transformation(), &(Matrix4::rotation_y(0.4) * Matrix4::scaling(1.0, 1.5, 1.0)));
+    assert_eq!(pyramid.len(), 2);
+    for named in pyramid.children() {
+        let named = named.as_any().downcast_ref::<Group>().unwrap();
+        for triangle in named.children() {
+            assert_eq!(triangle.material().color, Color::new(1.0, 0.7, 0.2));
+        }
+    }
+    let e = error_for(&format!("{}obj file=no_such_file.obj\n", CAMERA));
+    assert_eq!(e.line, Some(2));
+    Ok(())
+}
+
+#[test]
+fn test_scene_obj_threshold() -> Result<(), Box<dyn Error>> {
+    let scene = Scene::from_str(&format!("{}obj file=scenes/pyramid.obj threshold=1\n", CAMERA))?;
+    let pyramid = scene.world.objects[0].as_any().downcast_ref::<Group>().unwrap();
+    // dividing splits up the groups from the file, but doesn't lose any triangles
+    let r = Ray::new(Tuple::new_point(0.0, 5.0, 0.1), Tuple::new_vector(0.0, -1.0, 0.0));
+    assert_eq!(pyramid.intersections(&r).len(), 2);
+    let e = error_for(&format!("{}obj file=scenes/pyramid.obj threshold=few\n", CAMERA));
+    assert!(e.message.contains("few"));
+    Ok(())
+}
+
+#[test]
+fn test_scene_csg_takes_last_two_shapes() -> Result<(), Box<dyn Error>> {
+    let scene = Scene::from_str(&format!("{}
+        plane
+        cube color=1,0,0
+        sphere scale=1.5,1.5,1.5
+        csg operation=intersection translate=0,1,0
+    ", CAMERA))?;
+    assert_eq!(scene.world.objects.len(), 2);
+    let csg = scene.world.objects[1].as_any().downcast_ref::<Csg>().unwrap();
+    assert_eq!(csg.operation(), CsgOperation::Intersection);
+    assert_eq!(csg.transformation(), &Matrix4::translation(0.0, 1.0, 0.0));
+    assert_eq!(csg.left().material().color, Color::new(1.0, 0.0, 0.0));
+    assert!(csg.right().as_any().is::<Sphere>());
+    Ok(())
+}
+
+#[test]
+fn test_scene_error_bad_csg() {
+    let e = error_for(&format!("{}sphere\ncsg operation=union\n", CAMERA));
+    assert_eq!(e.line, Some(3));
+    let e = error_for(&format!("{}sphere\nsphere\ncsg operation=xor\n", CAMERA));
+    assert!(e.message.contains("xor"));
+    let e = error_for(&format!("{}sphere\nsphere\ncsg operation=union color=1,0,0\n", CAMERA));
+    assert!(e.message.contains("color"));
+}
+
+#[test]
+fn test_load_die_scene() -> Result<(), Box<dyn Error>> {
+    let scene = Scene::load("scenes/die.scene")?;
+    assert_eq!(scene.world.objects.len(), 2);
+    assert!(scene.world.objects[1].as_any().is::<Csg>());
+    Ok(())
+}
+
+#[test]
+fn test_scene_torus() -> Result<(), Box<dyn Error>> {
+    let scene = Scene::from_str(&format!("{}torus minor_radius=0.5\n", CAMERA))?;
+    let torus = scene.world.objects[0].as_any().downcast_ref::<Torus>().unwrap();
+    assert_eq!((torus.major_radius, torus.minor_radius), (1.0, 0.5));
+    assert!(Scene::load("scenes/torus.scene").is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_scene_error_torus_radii() {
+    let e = error_for(&format!("{}torus major_radius=0\n", CAMERA));
+    assert_eq!(e.line, Some(2));
+    assert_eq!(e.message, "a torus needs both radii above 0");
+    assert_eq!(error_for(&format!("{}torus minor_radius=-0.25\n", CAMERA)).message, "a torus needs both radii above 0");
+    assert_eq!(error_for(&format!("{}torus major_radius=1 minor_radius=1\n", CAMERA)).message,
+        "`minor_radius` has to be less than `major_radius` (1) but found `1`");
+}
+
+#[test]
+fn test_scene_sphere_center_and_radius() -> Result<(), Box<dyn Error>> {
+    let scene = Scene::from_str(&format!("{}
+        sphere
+        sphere center=1,2,3 radius=0.5 translate=0,1,0
+    ", CAMERA))?;
+    let unit = scene.world.objects[0].as_any().downcast_ref::<Sphere>().unwrap();
+    assert_eq!(*unit, Sphere::new_unit());
+    let small = scene.world.objects[1].as_any().downcast_ref::<Sphere>().unwrap();
+    assert_eq!((small.origin, small.radius), (Tuple::new_point(1.0, 2.0, 3.0), 0.5));
+    assert_eq!(small.transformation(), &Matrix4::translation(0.0, 1.0, 0.0));
+    Ok(())
+}
+
+#[test]
+fn test_scene_error_sphere_radius() {
+    let e = error_for(&format!("{}sphere radius=0\n", CAMERA));
+    assert_eq!(e.line, Some(2));
+    assert_eq!(e.message, "`radius` expects a number above 0 but found `0`");
+    let e = error_for(&format!("{}sphere radius=-1\n", CAMERA));
+    assert_eq!(e.message, "`radius` expects a number above 0 but found `-1`");
+}
+
+#[test]
+fn test_scene_quadric() -> Result<(), Box<dyn Error>> {
+    let scene = Scene::from_str(&format!("{}
+        quadric kind=paraboloid maximum=2 closed=true
+        quadric coefficients=1,1,1,0,0,0,0,0,0,-4
+    ", CAMERA))?;
+    let bowl = scene.world.objects[0].as_any().downcast_ref::<Quadric>().unwrap();
+    assert_eq!(*bowl, Quadric::paraboloid().truncated(Float::NEG_INFINITY, 2.0, true));
+    let sphere = scene.world.objects[1].as_any().downcast_ref::<Quadric>().unwrap();
+    assert_eq!(sphere.coefficients, [1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, -4.0]);
+    assert!(Scene::load("scenes/quadrics.scene").is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_scene_quadric_errors() {
+    assert_eq!(error_for(&format!("{}quadric\n", CAMERA)).message, "quadric needs a `kind` or `coefficients`");
+    assert_eq!(error_for(&format!("{}quadric kind=cone\n", CAMERA)).message,
+        "`kind` expects paraboloid, hyperboloid or two_sheet_hyperboloid but found `cone`");
+    assert_eq!(error_for(&format!("{}quadric coefficients=1,2,3\n", CAMERA)).message,
+        "`coefficients` expects 10 comma-separated numbers but found `1,2,3`");
+}
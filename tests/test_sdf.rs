@@ -0,0 +1,229 @@
+use book_renderer::{
+    sdf::{Sdf, DistanceField, SphereField, BoxField, RoundedBoxField, TorusField, SmoothUnion, SmoothSubtraction, Repeat},
+    sphere::Sphere, csg::{Csg, CsgOperation}, world::World, light::Light, color::Color, ray::Ray,
+    matrix4::Matrix4, tuple::{Tuple, Point}, intersectable::Intersectable, intersection::Intersection,
+    util::{feq, feq_precision, Float},
+};
+
+mod common;
+use common::{ts, assert_ts};
+
+fn point(x: Float, y: Float, z: Float) -> Point {
+    Tuple::new_point(x, y, z)
+}
+
+fn unit_box() -> BoxField {
+    BoxField { half_size: Tuple::new_vector(1.0, 1.0, 1.0) }
+}
+
+#[test]
+fn test_sphere_field() {
+    let f = SphereField { radius: 1.0 };
+    assert_eq!(f.distance(&Tuple::origin_point()), -1.0);
+    assert_eq!(f.distance(&point(0.0, 3.0, 0.0)), 2.0);
+}
+
+#[test]
+fn test_box_field() {
+    let f = unit_box();
+    assert_eq!(f.distance(&point(2.0, 0.0, 0.0)), 1.0);
+    assert_eq!(f.distance(&point(2.0, 2.0, 0.0)), 2.0_f64.sqrt());
+    assert_eq!(f.distance(&point(0.5, 0.0, 0.0)), -0.5);
+    assert_eq!(f.distance(&point(1.0, 0.3, -0.2)), 0.0);
+}
+
+#[test]
+fn test_rounded_box_field() {
+    let f = RoundedBoxField { half_size: Tuple::new_vector(1.0, 1.0, 1.0), radius: 0.25 };
+    // the faces are where the box's are; the corners are rounded off
+    assert_eq!(f.distance(&point(1.0, 0.0, 0.0)), 0.0);
+    assert!(feq(&f.distance(&point(2.0, 2.0, 0.0)), &(1.25 * 2.0_f64.sqrt() - 0.25)));
+    assert!(f.distance(&point(0.99, 0.99, 0.99)) > 0.0);
+}
+
+#[test]
+fn test_torus_field() {
+    let f = TorusField { major_radius: 1.0, minor_radius: 0.25 };
+    assert_eq!(f.distance(&point(1.25, 0.0, 0.0)), 0.0);
+    assert_eq!(f.distance(&point(0.0, 0.0, -1.0)), -0.25);
+    assert_eq!(f.distance(&Tuple::origin_point()), 0.75);
+}
+
+#[test]
+fn test_smooth_union() {
+    let a = || SphereField { radius: 1.0 };
+    let b = || SphereField { radius: 1.0 };
+    let far = point(0.0, 5.0, 0.0);
+    let sharp = SmoothUnion::new(a(), b(), 0.0);
+    assert_eq!(sharp.distance(&far), 4.0);
+    // blending fills in between the shapes, so it's never further than the plain union
+    let smooth = SmoothUnion::new(a(), b(), 0.5);
+    assert!(smooth.distance(&far) < 4.0);
+    // and it's the same away from where they meet
+    let apart = SmoothUnion::new(a(), Repeat::new(b(), Tuple::new_vector(0.0, 0.0, 0.0)), 0.5);
+    assert_eq!(apart.distance(&point(0.0, 0.0, 5.0)), 4.0 - 0.125);
+}
+
+#[test]
+fn test_smooth_subtraction() {
+    let cut = SmoothSubtraction::new(unit_box(), SphereField { radius: 1.2 }, 0.0);
+    // the middle is gone, the corners are left
+    assert!(cut.distance(&Tuple::origin_point()) > 0.0);
+    assert!(cut.distance(&point(0.95, 0.95, 0.95)) < 0.0);
+    let smooth = SmoothSubtraction::new(unit_box(), SphereField { radius: 1.2 }, 0.2);
+    assert!(smooth.distance(&point(0.95, 0.95, 0.95)) >= cut.distance(&point(0.95, 0.95, 0.95)));
+    assert_eq!(smooth.bounds(), unit_box().bounds());
+}
+
+#[test]
+fn test_repeat() {
+    let f = Repeat::new(SphereField { radius: 1.0 }, Tuple::new_vector(4.0, 0.0, 0.0));
+    assert!(feq(&f.distance(&point(8.5, 0.0, 0.0)), &-0.5));
+    assert!(feq(&f.distance(&point(-2.0, 0.0, 0.0)), &1.0));
+    // only repeated along x
+    assert_eq!(f.distance(&point(0.0, 4.0, 0.0)), 3.0);
+    let b = f.bounds();
+    assert_eq!((b.min.x, b.max.x, b.min.y, b.max.y), (Float::NEG_INFINITY, Float::INFINITY, -1.0, 1.0));
+}
+
+#[test]
+fn test_sdf_sphere_matches_sphere() {
+    let sdf = Sdf::new(SphereField { radius: 1.0 });
+    let sphere = Sphere::new_unit();
+    let r = Ray::new(point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_ts(ts(&sdf, &r), &[4.0, 6.0]);
+    let r = Ray::new(point(0.2, 0.3, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_ts(ts(&sdf, &r), &ts(&sphere, &r));
+    let p = point(0.0, 0.0, -1.0);
+    assert_eq!(sdf.surface_normal(&p), sphere.surface_normal(&p));
+    let p = point(0.6, 0.0, 0.8);
+    assert_eq!(sdf.surface_normal(&p), sphere.surface_normal(&p));
+}
+
+#[test]
+fn test_sdf_ray_misses() {
+    let sdf = Sdf::new(SphereField { radius: 1.0 });
+    let r = Ray::new(point(0.0, 1.1, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert!(sdf.intersections(&r).is_empty());
+}
+
+#[test]
+fn test_sdf_ray_inside() {
+    // the way in is behind the ray, as for the analytic shapes
+    let sdf = Sdf::new(unit_box());
+    let r = Ray::new(Tuple::origin_point(), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_ts(ts(&sdf, &r), &[-1.0, 1.0]);
+}
+
+#[test]
+fn test_sdf_box_faces_and_normals() {
+    let sdf = Sdf::new(unit_box());
+    let r = Ray::new(point(0.5, 5.0, 0.5), Tuple::new_vector(0.0, -1.0, 0.0));
+    assert_ts(ts(&sdf, &r), &[4.0, 6.0]);
+    assert_eq!(sdf.surface_normal(&point(0.5, 1.0, 0.5)), Tuple::new_vector(0.0, 1.0, 0.0));
+    assert_eq!(sdf.surface_normal(&point(-1.0, 0.2, 0.5)), Tuple::new_vector(-1.0, 0.0, 0.0));
+}
+
+#[test]
+fn test_transformed_sdf() {
+    let mut sdf = Sdf::new(SphereField { radius: 1.0 });
+    sdf.set_transform(Matrix4::translation(0.0, 0.0, 5.0) * Matrix4::scaling(2.0, 2.0, 2.0)).unwrap();
+    let r = Ray::new(Tuple::origin_point(), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_ts(ts(&sdf, &r), &[3.0, 7.0]);
+}
+
+#[test]
+fn test_unbounded_sdf() {
+    // a row of spheres along x, 4 apart
+    let sdf = Sdf::new(Repeat::new(SphereField { radius: 1.0 }, Tuple::new_vector(4.0, 0.0, 0.0)));
+    let r = Ray::new(point(40.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_ts(ts(&sdf, &r), &[4.0, 6.0]);
+    let r = Ray::new(point(42.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert!(sdf.intersections(&r).is_empty());
+}
+
+#[test]
+fn test_sdf_max_steps_and_epsilon() {
+    let mut sdf = Sdf::new(TorusField { major_radius: 1.0, minor_radius: 0.25 });
+    let r = Ray::new(point(-5.0, 0.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert_ts(ts(&sdf, &r), &[3.75, 4.25, 5.75, 6.25]);
+    sdf.max_steps = 0;
+    assert!(sdf.intersections(&r).is_empty());
+    sdf.max_steps = 512;
+    sdf.epsilon = 0.01;
+    let xs = ts(&sdf, &r);
+    assert_eq!(xs.len(), 4);
+    assert!(feq_precision(&xs[0], &3.75, 2));
+}
+
+#[test]
+fn test_sdf_ray_starting_inside_repeat() {
+    // the ray starts in the middle of one of the spheres, so the way in is behind it
+    let mut sdf = Sdf::new(Repeat::new(SphereField { radius: 1.0 }, Tuple::new_vector(4.0, 0.0, 0.0)));
+    sdf.mut_material().refractive_index = 1.5;
+    let r = Ray::new(Tuple::origin_point(), Tuple::new_vector(1.0, 0.0, 0.0));
+    let xs = sdf.intersections(&r);
+    assert_eq!(xs.len() % 2, 0);
+    assert_ts(xs.iter().take(4).map(|x| x.t).collect(), &[-1.0, 1.0, 3.0, 5.0]);
+    // so leaving it goes from glass to air
+    let comps = xs[1].precompute_with_intersections(&r, &xs);
+    assert_eq!((comps.n1, comps.n2), (1.5, 1.0));
+    // even when the way in is out of reach, the way out isn't left on its own
+    let r = Ray::new(point(0.3, 0.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0));
+    for max_steps in 0..40 {
+        sdf.max_steps = max_steps;
+        assert_eq!(sdf.intersections(&r).len() % 2, 0, "{} steps", max_steps);
+    }
+}
+
+#[test]
+fn test_sdf_out_of_steps_keeps_hits_paired() {
+    // a long way through a long box: the way in is found long before the way out
+    let mut sdf = Sdf::new(BoxField { half_size: Tuple::new_vector(100.0, 1.0, 1.0) });
+    let r = Ray::new(point(-105.0, 0.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert_ts(ts(&sdf, &r), &[5.0, 205.0]);
+    sdf.max_steps = 20;
+    assert!(sdf.intersections(&r).is_empty());
+    for max_steps in 0..100 {
+        sdf.max_steps = max_steps;
+        assert_eq!(sdf.intersections(&r).len() % 2, 0, "{} steps", max_steps);
+    }
+}
+
+#[test]
+fn test_sdf_in_world_lights_like_sphere() {
+    let light = || vec![Light::new_point(point(-10.0, 10.0, -10.0), Color::white(1.0))];
+    let analytic = World::new(light(), vec![Box::new(Sphere::new_unit())]);
+    let marched = World::new(light(), vec![Box::new(Sdf::new(SphereField { radius: 1.0 }))]);
+    for (x, y) in [(0.0, 0.0), (0.3, 0.4), (-0.5, -0.6), (0.9, 0.0)] {
+        let r = Ray::new(point(x, y, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+        let (a, m) = (analytic.color_at(&r), marched.color_at(&r));
+        assert!(feq_precision(&a.red, &m.red, 4) && feq_precision(&a.green, &m.green, 4) && feq_precision(&a.blue, &m.blue, 4),
+            "{:?} is not {:?}", m, a);
+    }
+}
+
+#[test]
+fn test_sdf_does_not_shadow_itself() {
+    let w = World::new(
+        vec![Light::new_point(point(0.0, 10.0, -10.0), Color::white(1.0))],
+        vec![Box::new(Sdf::new(RoundedBoxField { half_size: Tuple::new_vector(1.0, 1.0, 1.0), radius: 0.3 }))],
+    );
+    let r = Ray::new(point(0.2, 5.0, -0.3), Tuple::new_vector(0.0, -1.0, 0.0));
+    let xs = w.intersections(&r);
+    let hit = Intersection::hit(&xs).unwrap();
+    let comps = hit.precompute(&r);
+    assert!(!w.is_shadowed(&w.lights[0], &comps.over_point));
+}
+
+#[test]
+fn test_sdf_in_csg() {
+    // a box with a hollow in it, made from an SDF and a sphere
+    let mut hole = Sphere::new_unit();
+    hole.set_transform(Matrix4::scaling(0.5, 0.5, 0.5)).unwrap();
+    let c = Csg::new(CsgOperation::Difference, Box::new(Sdf::new(unit_box())), Box::new(hole));
+    let r = Ray::new(point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_ts(ts(&c, &r), &[4.0, 4.5, 5.5, 6.0]);
+    let r = Ray::new(point(0.9, 0.9, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_ts(ts(&c, &r), &[4.0, 6.0]);
+}
@@ -0,0 +1,112 @@
+use std::f64::consts::PI;
+
+use book_renderer::{bounds::BoundingBox, shape::Shape, intersectable::Intersectable, matrix4::Matrix4, material::Material, ray::Ray, tuple::Tuple};
+
+mod common;
+use common::TestShape;
+
+// pg 119
+#[test]
+fn test_book_shape_default_transform() {
+    let s = TestShape::new();
+    assert_eq!(s.transformation(), &Matrix4::identity());
+}
+
+// pg 119
+#[test]
+fn test_book_shape_assign_transform() {
+    let mut s = TestShape::new();
+    s.set_transform(Matrix4::translation(2.0, 3.0, 4.0)).unwrap();
+    assert_eq!(s.transformation(), &Matrix4::translation(2.0, 3.0, 4.0));
+}
+
+// pg 119
+#[test]
+fn test_book_shape_default_material() {
+    let s = TestShape::new();
+    assert_eq!(s.material(), &Material::default());
+}
+
+// pg 119
+#[test]
+fn test_book_shape_assign_material() {
+    let mut s = TestShape::new();
+    let m = Material { ambient: 1.0, ..Material::default() };
+    *s.mut_material() = m.clone();
+    assert_eq!(s.material(), &m);
+}
+
+// pg 120
+#[test]
+fn test_book_intersect_scaled_shape() {
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let mut s = TestShape::new();
+    s.set_transform(Matrix4::scaling(2.0, 2.0, 2.0)).unwrap();
+    s.intersections(&r);
+    let saved = s.saved_ray().unwrap();
+    assert_eq!(saved.origin, Tuple::new_point(0.0, 0.0, -2.5));
+    assert_eq!(saved.direction, Tuple::new_vector(0.0, 0.0, 0.5));
+}
+
+// pg 120
+#[test]
+fn test_book_intersect_translated_shape() {
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let mut s = TestShape::new();
+    s.set_transform(Matrix4::translation(5.0, 0.0, 0.0)).unwrap();
+    s.intersections(&r);
+    let saved = s.saved_ray().unwrap();
+    assert_eq!(saved.origin, Tuple::new_point(-5.0, 0.0, -5.0));
+    assert_eq!(saved.direction, Tuple::new_vector(0.0, 0.0, 1.0));
+}
+
+// pg 121
+#[test]
+fn test_book_normal_translated_shape() {
+    let mut s = TestShape::new();
+    s.set_transform(Matrix4::translation(0.0, 1.0, 0.0)).unwrap();
+    let n = s.surface_normal(&Tuple::new_point(0.0, 1.70711, -0.70711));
+    assert_eq!(n, Tuple::new_vector(0.0, 0.70711, -0.70711));
+}
+
+// pg 121
+#[test]
+fn test_book_normal_transformed_shape() {
+    let mut s = TestShape::new();
+    s.set_transform(Matrix4::scaling(1.0, 0.5, 1.0) * Matrix4::rotation_z(PI / 5.0)).unwrap();
+    let n = s.surface_normal(&Tuple::new_point(0.0, 2.0_f64.sqrt() / 2.0, -2.0_f64.sqrt() / 2.0));
+    assert_eq!(n, Tuple::new_vector(0.0, 0.97014, -0.24254));
+}
+
+#[test]
+fn test_shape_caches_inverse() {
+    let mut s = Shape::new();
+    s.set_transform(Matrix4::scaling(2.0, 4.0, 8.0)).unwrap();
+    assert_eq!(s.inverse(), &Matrix4::scaling(0.5, 0.25, 0.125));
+    assert_eq!(s.inverse_transpose(), &Matrix4::scaling(0.5, 0.25, 0.125));
+}
+
+#[test]
+fn test_shape_rejects_singular_transform() {
+    let mut s = TestShape::new();
+    s.set_transform(Matrix4::translation(1.0, 2.0, 3.0)).unwrap();
+    assert!(s.set_transform(Matrix4::scaling(0.0, 1.0, 1.0)).is_err());
+    assert_eq!(s.transformation(), &Matrix4::translation(1.0, 2.0, 3.0));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_test_shape_bounds() {
+    let s = TestShape::new();
+    assert_eq!(s.bounds(), BoundingBox::new(Tuple::new_point(-1.0, -1.0, -1.0), Tuple::new_point(1.0, 1.0, 1.0)));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_book_parent_space_bounds() {
+    let mut s = TestShape::new();
+    s.set_transform(Matrix4::translation(1.0, -3.0, 5.0) * Matrix4::scaling(0.5, 2.0, 4.0)).unwrap();
+    let bounds = s.parent_space_bounds();
+    assert_eq!(bounds.min, Tuple::new_point(0.5, -5.0, 1.0));
+    assert_eq!(bounds.max, Tuple::new_point(1.5, -1.0, 9.0));
+}
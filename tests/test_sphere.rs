@@ -1,25 +1,28 @@
 use std::{error::Error, f64::consts::PI};
 
-use book_renderer::{sphere::Sphere, matrix::Matrix, ray::Ray, tuple::Tuple, util::Float, material::Material, intersectable::Intersectable};
+use book_renderer::{
+    sphere::Sphere, matrix4::Matrix4, ray::Ray, tuple::Tuple, util::Float, material::Material, intersectable::Intersectable,
+    bounds::BoundingBox, camera::Camera, world::World, light::Light, plane::Plane, color::Color,
+};
 
 #[test]
 fn test_book_sphere_default_transform() {
     let s = Sphere::new_unit();
-    assert_eq!(s.transform, Matrix::identity(4));
+    assert_eq!(s.transformation(), &Matrix4::identity());
 }
 
 #[test]
 fn test_book_sphere_mutable_transform() {
     let mut s = Sphere::new_unit();
-    s.set_transform(Matrix::translation(2.0, 3.0, 4.0));
-    assert_eq!(s.transform, Matrix::translation(2.0, 3.0, 4.0));
+    s.set_transform(Matrix4::translation(2.0, 3.0, 4.0)).unwrap();
+    assert_eq!(s.transformation(), &Matrix4::translation(2.0, 3.0, 4.0));
 }
 
 #[test]
 fn test_book_intersect_scaled_sphere() {
     let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
     let mut s = Sphere::new_unit();
-    s.set_transform(Matrix::scaling(2.0, 2.0, 2.0));
+    s.set_transform(Matrix4::scaling(2.0, 2.0, 2.0)).unwrap();
     let xs = s.intersections(&r);
     assert_eq!(xs.len(), 2);
     assert_eq!(xs[0].t, 3.0);
@@ -30,7 +33,7 @@ fn test_book_intersect_scaled_sphere() {
 fn test_book_intersect_translated_sphere() {
     let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
     let mut s = Sphere::new_unit();
-    s.set_transform(Matrix::translation(5.0, 0.0, 0.0));
+    s.set_transform(Matrix4::translation(5.0, 0.0, 0.0)).unwrap();
     let xs = s.intersections(&r);
     assert_eq!(xs.len(), 0);
 }
@@ -73,7 +76,7 @@ fn test_book_surface_normal_normalized() -> Result<(), Box<dyn Error>> {
 #[test]
 fn test_book_translated_normal() {
     let mut s = Sphere::new_unit();
-    s.set_transform(Matrix::translation(0.0, 1.0, 0.0));
+    s.set_transform(Matrix4::translation(0.0, 1.0, 0.0)).unwrap();
     let n = s.surface_normal(&Tuple::new_point(0.0, 1.70711, -0.70711));
     assert_eq!(n, Tuple::new_vector(0.0, 0.70711, -0.70711));
 }
@@ -81,7 +84,7 @@ fn test_book_translated_normal() {
 #[test]
 fn test_book_scaled_rotated_normal() {
     let mut s = Sphere::new_unit();
-    s.set_transform(Matrix::scaling(1.0, 0.5, 1.0) * Matrix::rotation_z(PI/5.0));
+    s.set_transform(Matrix4::scaling(1.0, 0.5, 1.0) * Matrix4::rotation_z(PI/5.0)).unwrap();
     let n = s.surface_normal(&Tuple::new_point(0.0, 2.0_f64.sqrt()/2.0, -2.0_f64.sqrt()/2.0));
     assert_eq!(n, Tuple::new_vector(0.0, 0.97014, -0.24254));
 }
@@ -89,14 +92,113 @@ fn test_book_scaled_rotated_normal() {
 #[test]
 fn test_book_default_material() {
     let s = Sphere::new_unit();
-    assert_eq!(s.material, Material::default());
+    assert_eq!(s.material(), &Material::default());
 }
 
 #[test]
 fn test_book_modify_material() {
     let mut s = Sphere::new_unit();
     assert_eq!(s, Sphere::new_unit());
-    s.material.ambient = 1.0;
-    assert_ne!(s.material, Material::default());
+    s.mut_material().ambient = 1.0;
+    assert_ne!(s.material(), &Material::default());
     assert_ne!(s, Sphere::new_unit());
-}
\ No newline at end of file
+}
+// pg 151
+#[test]
+fn test_book_glass_sphere() {
+    let s = Sphere::glass();
+    assert_eq!(s.transformation(), &Matrix4::identity());
+    assert_eq!(s.material().transparency, 1.0);
+    assert_eq!(s.material().refractive_index, 1.5);
+}
+
+#[test]
+fn test_sphere_with_origin_and_radius() {
+    let s = Sphere::new(Tuple::new_point(1.0, 2.0, 3.0), 2.0);
+    assert_eq!(s.origin, Tuple::new_point(1.0, 2.0, 3.0));
+    assert_eq!(s.radius, 2.0);
+    assert_eq!(s.transformation(), &Matrix4::identity());
+    assert_eq!(Sphere::new(Tuple::origin_point(), 1.0), Sphere::new_unit());
+}
+
+#[test]
+fn test_intersect_sphere_with_origin_and_radius() {
+    let s = Sphere::new(Tuple::new_point(0.0, 0.0, 5.0), 2.0);
+    let r = Ray::new(Tuple::origin_point(), Tuple::new_vector(0.0, 0.0, 1.0));
+    let xs = s.intersections(&r);
+    assert_eq!(xs.iter().map(|x| x.t).collect::<Vec<Float>>(), vec![3.0, 7.0]);
+    // just grazing the side
+    let r = Ray::new(Tuple::new_point(2.0, 0.0, 0.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_eq!(s.intersections(&r).iter().map(|x| x.t).collect::<Vec<Float>>(), vec![5.0]);
+    let r = Ray::new(Tuple::new_point(2.1, 0.0, 0.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert!(s.intersections(&r).is_empty());
+}
+
+#[test]
+fn test_normal_on_sphere_with_origin_and_radius() {
+    let s = Sphere::new(Tuple::new_point(1.0, 0.0, 0.0), 3.0);
+    assert_eq!(s.surface_normal(&Tuple::new_point(4.0, 0.0, 0.0)), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert_eq!(s.surface_normal(&Tuple::new_point(1.0, 0.0, -3.0)), Tuple::new_vector(0.0, 0.0, -1.0));
+    let a = 3.0 / 3.0_f64.sqrt();
+    let n = s.surface_normal(&Tuple::new_point(1.0 + a, a, a));
+    assert_eq!(n, Tuple::new_vector(1.0, 1.0, 1.0).normalize().unwrap());
+}
+
+#[test]
+fn test_origin_and_radius_combine_with_transform() {
+    // moved and sized by its fields, then halved by the transform: a unit sphere at (0.5, 0, 0)
+    let mut s = Sphere::new(Tuple::new_point(1.0, 0.0, 0.0), 2.0);
+    s.set_transform(Matrix4::scaling(0.5, 0.5, 0.5)).unwrap();
+    let r = Ray::new(Tuple::new_point(0.5, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_eq!(s.intersections(&r).iter().map(|x| x.t).collect::<Vec<Float>>(), vec![4.0, 6.0]);
+    assert_eq!(s.surface_normal(&Tuple::new_point(1.5, 0.0, 0.0)), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert_eq!(s.parent_space_bounds(), BoundingBox::new(Tuple::new_point(-0.5, -1.0, -1.0), Tuple::new_point(1.5, 1.0, 1.0)));
+}
+
+// bonus chapter: bounding boxes
+#[test]
+fn test_bounds_of_sphere_with_origin_and_radius() {
+    let s = Sphere::new(Tuple::new_point(1.0, -2.0, 3.0), 0.5);
+    assert_eq!(s.bounds(), BoundingBox::new(Tuple::new_point(0.5, -2.5, 2.5), Tuple::new_point(1.5, -1.5, 3.5)));
+}
+
+// The same scene with its spheres placed by their fields and by their transforms
+fn scene(spheres: Vec<Sphere>) -> World {
+    let mut floor = Plane::new();
+    floor.mut_material().reflective = 0.3;
+    let mut objects: Vec<Box<dyn Intersectable>> = vec![Box::new(floor)];
+    for (i, mut s) in spheres.into_iter().enumerate() {
+        s.mut_material().color = Color::new(0.2 * i as Float, 0.5, 1.0 - 0.2 * i as Float);
+        if i == 1 {
+            s.mut_material().transparency = 0.8;
+            s.mut_material().refractive_index = 1.5;
+        }
+        objects.push(Box::new(s));
+    }
+    World::new(vec![Light::new_point(Tuple::new_point(-10.0, 10.0, -10.0), Color::white(1.0))], objects)
+}
+
+#[test]
+fn test_origin_and_radius_render_like_transform() -> Result<(), Box<dyn Error>> {
+    let by_fields = scene(vec![
+        Sphere::new(Tuple::new_point(-1.5, 0.5, 0.0), 0.5),
+        Sphere::new(Tuple::new_point(0.0, 1.0, 0.0), 1.0),
+        Sphere::new(Tuple::new_point(1.5, 0.75, -0.5), 0.75),
+    ]);
+    let by_transform = scene([(-1.5, 0.5, 0.0, 0.5), (0.0, 1.0, 0.0, 1.0), (1.5, 0.75, -0.5, 0.75)].iter()
+        .map(|(x, y, z, r)| {
+            let mut s = Sphere::new_unit();
+            s.set_transform(Matrix4::translation(*x, *y, *z) * Matrix4::scaling(*r, *r, *r)).unwrap();
+            s
+        })
+        .collect());
+    let mut c = Camera::new(40, 20, PI / 3.0);
+    c.set_transform(Matrix4::view_transform(&Tuple::new_point(0.0, 1.5, -5.0), &Tuple::new_point(0.0, 1.0, 0.0), &Tuple::new_vector(0.0, 1.0, 0.0))?)?;
+    let (a, b) = (c.render(&by_fields), c.render(&by_transform));
+    for y in 0..20 {
+        for x in 0..40 {
+            assert_eq!(a[(x, y)], b[(x, y)], "pixel {}, {}", x, y);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use book_renderer::{torus::Torus, bounds::BoundingBox, matrix4::Matrix4, tuple::Tuple, util::feq, intersectable::Intersectable};
+
+mod common;
+use common::{ray, ts, assert_ts};
+
+#[test]
+fn test_ray_through_both_sides_of_ring() {
+    let torus = Torus::new(1.0, 0.25);
+    let r = ray(Tuple::new_point(-5.0, 0.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert_ts(ts(&torus, &r), &[3.75, 4.25, 5.75, 6.25]);
+}
+
+#[test]
+fn test_ray_through_tube_from_above() {
+    let torus = Torus::new(1.0, 0.25);
+    let r = ray(Tuple::new_point(1.0, 5.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0));
+    assert_ts(ts(&torus, &r), &[4.75, 5.25]);
+}
+
+#[test]
+fn test_ray_misses_torus() {
+    let torus = Torus::new(1.0, 0.25);
+    let cases = [
+        // down through the hole
+        (Tuple::new_point(0.0, 5.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0)),
+        // over the top
+        (Tuple::new_point(-5.0, 0.3, 0.0), Tuple::new_vector(1.0, 0.0, 0.0)),
+        // outside the ring
+        (Tuple::new_point(1.5, 5.0, 0.0), Tuple::new_vector(0.0, -1.0, 0.0)),
+    ];
+    for (origin, direction) in cases {
+        assert_ts(ts(&torus, &ray(origin, direction)), &[]);
+    }
+}
+
+#[test]
+fn test_ray_grazing_top_of_tube() {
+    // touches the top of the tube where it crosses the x axis on both sides, going in and
+    // straight back out each time
+    let torus = Torus::new(1.0, 0.25);
+    let r = ray(Tuple::new_point(-5.0, 0.25, 0.0), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert_ts(ts(&torus, &r), &[4.0, 4.0, 6.0, 6.0]);
+}
+
+#[test]
+fn test_ray_grazing_outside_of_ring() {
+    let torus = Torus::new(1.0, 0.25);
+    let r = ray(Tuple::new_point(-5.0, 0.0, 1.25), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert_ts(ts(&torus, &r), &[5.0, 5.0]);
+}
+
+#[test]
+fn test_ray_grazing_inside_of_hole() {
+    // touches the inner edge of the ring while passing through the tube on either side; the
+    // touch counts as going out and back in, so every hit still pairs up
+    let torus = Torus::new(1.0, 0.25);
+    let r = ray(Tuple::new_point(-5.0, 0.0, 0.75), Tuple::new_vector(1.0, 0.0, 0.0));
+    let xs = ts(&torus, &r);
+    assert_eq!(xs.len(), 4);
+    assert!(feq(&xs[1], &5.0) && feq(&xs[2], &5.0));
+    assert!(feq(&(xs[0] + xs[3]), &10.0));
+}
+
+#[test]
+fn test_ray_from_far_away() {
+    let torus = Torus::new(1.0, 0.25);
+    let r = ray(Tuple::new_point(-10000.0, 0.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0));
+    assert_ts(ts(&torus, &r), &[9998.75, 9999.25, 10000.75, 10001.25]);
+}
+
+#[test]
+fn test_ray_at_an_angle() {
+    let torus = Torus::new(2.0, 0.5);
+    // down through the near side of the tube, passing over the far side
+    let r = ray(Tuple::new_point(-4.0, 2.0, 0.1), Tuple::new_vector(1.0, -1.0, 0.0));
+    for x in torus.local_intersect(&r) {
+        let p = r.position(&x.t);
+        // every hit is on the surface of the tube
+        let from_ring = ((p.x * p.x + p.z * p.z).sqrt() - 2.0).hypot(p.y);
+        assert!(feq(&from_ring, &0.5), "{:?}", p);
+    }
+    assert_eq!(torus.local_intersect(&r).len(), 2);
+}
+
+#[test]
+fn test_normal_on_torus() {
+    let torus = Torus::new(1.0, 0.25);
+    let cases = [
+        (Tuple::new_point(1.25, 0.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0)),
+        (Tuple::new_point(0.75, 0.0, 0.0), Tuple::new_vector(-1.0, 0.0, 0.0)),
+        (Tuple::new_point(1.0, 0.25, 0.0), Tuple::new_vector(0.0, 1.0, 0.0)),
+        (Tuple::new_point(0.0, -0.25, -1.0), Tuple::new_vector(0.0, -1.0, 0.0)),
+        (Tuple::new_point(0.0, 0.0, -0.75), Tuple::new_vector(0.0, 0.0, 1.0)),
+    ];
+    for (point, normal) in cases {
+        assert_eq!(torus.local_normal_at(&point).normalize().unwrap(), normal);
+    }
+}
+
+#[test]
+fn test_transformed_torus() {
+    // stood up on its edge and moved back
+    let mut torus = Torus::new(1.0, 0.25);
+    torus.set_transform(Matrix4::translation(0.0, 0.0, 5.0) * Matrix4::rotation_x(std::f64::consts::PI / 2.0)).unwrap();
+    let r = ray(Tuple::new_point(0.0, 1.0, 0.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    assert_ts(ts(&torus, &r), &[4.75, 5.25]);
+    assert_eq!(torus.surface_normal(&Tuple::new_point(0.0, 1.0, 4.75)), Tuple::new_vector(0.0, 0.0, -1.0));
+}
+
+#[test]
+fn test_torus_bounds() {
+    let torus = Torus::new(2.0, 0.5);
+    assert_eq!(torus.bounds(), BoundingBox::new(Tuple::new_point(-2.5, -0.5, -2.5), Tuple::new_point(2.5, 0.5, 2.5)));
+}
@@ -0,0 +1,126 @@
+use book_renderer::{
+    triangle::Triangle, smooth_triangle::SmoothTriangle, ray::Ray, tuple::Tuple, matrix4::Matrix4,
+    intersection::Intersection, intersectable::Intersectable, util::feq,
+};
+
+fn book_triangle() -> Triangle {
+    Triangle::new(Tuple::new_point(0.0, 1.0, 0.0), Tuple::new_point(-1.0, 0.0, 0.0), Tuple::new_point(1.0, 0.0, 0.0))
+}
+
+fn book_smooth_triangle() -> SmoothTriangle {
+    SmoothTriangle::new(
+        Tuple::new_point(0.0, 1.0, 0.0), Tuple::new_point(-1.0, 0.0, 0.0), Tuple::new_point(1.0, 0.0, 0.0),
+        Tuple::new_vector(0.0, 1.0, 0.0), Tuple::new_vector(-1.0, 0.0, 0.0), Tuple::new_vector(1.0, 0.0, 0.0),
+    )
+}
+
+// pg 208
+#[test]
+fn test_book_construct_triangle() {
+    let t = book_triangle();
+    assert_eq!(t.p1(), &Tuple::new_point(0.0, 1.0, 0.0));
+    assert_eq!(t.p2(), &Tuple::new_point(-1.0, 0.0, 0.0));
+    assert_eq!(t.p3(), &Tuple::new_point(1.0, 0.0, 0.0));
+    assert_eq!(t.e1(), &Tuple::new_vector(-1.0, -1.0, 0.0));
+    assert_eq!(t.e2(), &Tuple::new_vector(1.0, -1.0, 0.0));
+    assert_eq!(t.normal(), &Tuple::new_vector(0.0, 0.0, -1.0));
+}
+
+// pg 209
+#[test]
+fn test_book_triangle_normal() {
+    let t = book_triangle();
+    assert_eq!(&t.local_normal_at(&Tuple::new_point(0.0, 0.5, 0.0)), t.normal());
+    assert_eq!(&t.local_normal_at(&Tuple::new_point(-0.5, 0.75, 0.0)), t.normal());
+    assert_eq!(&t.local_normal_at(&Tuple::new_point(0.5, 0.25, 0.0)), t.normal());
+}
+
+// pg 210
+#[test]
+fn test_book_ray_parallel_to_triangle() {
+    let t = book_triangle();
+    let r = Ray::new(Tuple::new_point(0.0, -1.0, -2.0), Tuple::new_vector(0.0, 1.0, 0.0));
+    assert!(t.local_intersect(&r).is_empty());
+}
+
+// pg 211
+#[test]
+fn test_book_ray_misses_triangle_edges() {
+    let t = book_triangle();
+    let direction = Tuple::new_vector(0.0, 0.0, 1.0);
+    // past the p1-p3 edge, the p1-p2 edge and the p2-p3 edge
+    for origin in [Tuple::new_point(1.0, 1.0, -2.0), Tuple::new_point(-1.0, 1.0, -2.0), Tuple::new_point(0.0, -1.0, -2.0)] {
+        assert!(t.local_intersect(&Ray::new(origin, direction)).is_empty());
+    }
+}
+
+// pg 212
+#[test]
+fn test_book_ray_hits_triangle() {
+    let t = book_triangle();
+    let r = Ray::new(Tuple::new_point(0.0, 0.5, -2.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let xs = t.local_intersect(&r);
+    assert_eq!(xs.len(), 1);
+    assert_eq!(xs[0].t, 2.0);
+}
+
+#[test]
+fn test_transformed_triangle() {
+    let mut t = book_triangle();
+    t.set_transform(Matrix4::rotation_y(std::f64::consts::PI)).unwrap();
+    assert_eq!(t.surface_normal(&Tuple::new_point(0.0, 0.5, 0.0)), Tuple::new_vector(0.0, 0.0, 1.0));
+    let r = Ray::new(Tuple::new_point(0.0, 0.5, 2.0), Tuple::new_vector(0.0, 0.0, -1.0));
+    assert_eq!(t.intersections(&r)[0].t, 2.0);
+}
+
+// pg 221
+#[test]
+fn test_book_intersection_with_uv() {
+    let s = book_triangle();
+    let i = Intersection::new_with_uv(3.5, &s, 0.2, 0.4);
+    assert_eq!(i.uv, Some((0.2, 0.4)));
+    assert_eq!(Intersection::new(3.5, &s).uv, None);
+}
+
+// pg 221
+#[test]
+fn test_book_construct_smooth_triangle() {
+    let tri = book_smooth_triangle();
+    assert_eq!(tri.p1(), &Tuple::new_point(0.0, 1.0, 0.0));
+    assert_eq!(tri.p2(), &Tuple::new_point(-1.0, 0.0, 0.0));
+    assert_eq!(tri.p3(), &Tuple::new_point(1.0, 0.0, 0.0));
+    assert_eq!(tri.n1(), &Tuple::new_vector(0.0, 1.0, 0.0));
+    assert_eq!(tri.n2(), &Tuple::new_vector(-1.0, 0.0, 0.0));
+    assert_eq!(tri.n3(), &Tuple::new_vector(1.0, 0.0, 0.0));
+}
+
+// pg 222
+#[test]
+fn test_book_smooth_triangle_stores_uv() {
+    let tri = book_smooth_triangle();
+    let r = Ray::new(Tuple::new_point(-0.2, 0.3, -2.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let xs = tri.local_intersect(&r);
+    let (u, v) = xs[0].uv.unwrap();
+    assert!(feq(&u, &0.45));
+    assert!(feq(&v, &0.25));
+}
+
+// pg 222
+#[test]
+fn test_book_smooth_triangle_interpolates_normal() {
+    let tri = book_smooth_triangle();
+    let i = Intersection::new_with_uv(1.0, &tri, 0.45, 0.25);
+    let n = tri.surface_normal_at_hit(&Tuple::origin_point(), &i);
+    assert_eq!(n, Tuple::new_vector(-0.5547, 0.83205, 0.0));
+}
+
+// pg 223
+#[test]
+fn test_book_smooth_triangle_precompute_normal() {
+    let tri = book_smooth_triangle();
+    let i = Intersection::new_with_uv(1.0, &tri, 0.45, 0.25);
+    let r = Ray::new(Tuple::new_point(-0.2, 0.3, -2.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let xs = vec![i];
+    let comps = xs[0].precompute_with_intersections(&r, &xs);
+    assert_eq!(comps.normalv, Tuple::new_vector(-0.5547, 0.83205, 0.0));
+}
@@ -1,16 +1,17 @@
 use std::ops::IndexMut;
 
-use book_renderer::{world::World, light::Light, tuple::Tuple, color::Color, matrix::Matrix, ray::Ray, intersection::Intersection};
+use book_renderer::{world::{World, DEFAULT_MAX_DEPTH}, sphere::Sphere, plane::Plane, light::Light, tuple::{Tuple, Point}, color::{Color, BLACK}, matrix4::Matrix4, ray::Ray, intersection::Intersection, intersectable::Intersectable, util::Float, pattern::{Pattern, PatternSpace}};
 
 
 #[test]
 fn test_book_default_world() {
     let mut w = World::default();
-    assert_eq!(w.light, Light::new_point(Tuple::new_point(-10.0, 10.0, -10.0), Color::white(1.0)));
-    assert_eq!(w.objects[0].transformation(), &Matrix::identity(4));
+    assert_eq!(w.lights.len(), 1);
+    assert_eq!(w.light(), Some(&Light::new_point(Tuple::new_point(-10.0, 10.0, -10.0), Color::white(1.0))));
+    assert_eq!(w.objects[0].transformation(), &Matrix4::identity());
     assert_eq!(w.objects.index_mut(0).mut_material().diffuse, 0.7);
     assert_eq!(w.objects[0].mut_material().color, Color::new(0.8, 1.0, 0.6));
-    assert_eq!(w.objects[1].transformation(), &Matrix::scaling(0.5, 0.5, 0.5));
+    assert_eq!(w.objects[1].transformation(), &Matrix4::scaling(0.5, 0.5, 0.5));
     assert_eq!(w.objects[1].mut_material().diffuse, 0.9);
     assert_eq!(w.objects[1].mut_material().color, Color::white(1.0));
 }
@@ -33,7 +34,7 @@ fn test_book_shade_intersection() {
     let w = World::default();
     let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
     let shape = w.objects[0].as_ref();
-    let i = Intersection{ t: 4.0, object: shape };
+    let i = Intersection::new(4.0, shape);
     let comps = i.precompute(&r);
     let c = w.shade_hit(&comps);
     assert_eq!(c, Color::new(0.38066, 0.47583, 0.2855));
@@ -43,10 +44,10 @@ fn test_book_shade_intersection() {
 #[test]
 fn test_book_shade_intersection_internal() {
     let mut w = World::default();
-    w.light = Light::new_point(Tuple::new_point(0.0, 0.25, 0.0), Color::white(1.0));
+    w.set_light(Light::new_point(Tuple::new_point(0.0, 0.25, 0.0), Color::white(1.0)));
     let r = Ray::new(Tuple::new_point(0.0, 0.0, 0.0), Tuple::new_vector(0.0, 0.0, 1.0));
     let shape = w.objects[1].as_ref();
-    let i = Intersection{ t: 0.5, object: shape };
+    let i = Intersection::new(0.5, shape);
     let comps = i.precompute(&r);
     let c = w.shade_hit(&comps);
     assert_eq!(c, Color::new(0.90498, 0.90498, 0.90498));
@@ -76,4 +77,311 @@ fn test_book_color_hit_behind_ray() {
     w.objects[1].mut_material().ambient = 1.0;
     let r = Ray::new(Tuple::new_point(0.0, 0.0, 0.75), Tuple::new_vector(0.0, 0.0, -1.0));
     assert_eq!(w.color_at(&r), w.objects[1].material().color);
-}
\ No newline at end of file
+}
+// pg 111
+#[test]
+fn test_book_no_shadow_nothing_collinear() {
+    let w = World::default();
+    let p = Tuple::new_point(0.0, 10.0, 0.0);
+    assert!(!w.is_shadowed(w.light().unwrap(), &p));
+}
+
+// pg 112
+#[test]
+fn test_book_shadow_object_between_point_and_light() {
+    let w = World::default();
+    let p = Tuple::new_point(10.0, -10.0, 10.0);
+    assert!(w.is_shadowed(w.light().unwrap(), &p));
+}
+
+// pg 112
+#[test]
+fn test_book_no_shadow_object_behind_light() {
+    let w = World::default();
+    let p = Tuple::new_point(-20.0, 20.0, -20.0);
+    assert!(!w.is_shadowed(w.light().unwrap(), &p));
+}
+
+// pg 112
+#[test]
+fn test_book_no_shadow_object_behind_point() {
+    let w = World::default();
+    let p = Tuple::new_point(-2.0, 2.0, -2.0);
+    assert!(!w.is_shadowed(w.light().unwrap(), &p));
+}
+
+#[test]
+fn test_no_shadow_at_light() {
+    // a light sitting right on the surface of the outer sphere
+    let mut w = World::default();
+    w.set_light(Light::new_point(Tuple::new_point(0.0, 0.0, -1.0), Color::white(1.0)));
+    assert!(!w.is_shadowed(w.light().unwrap(), &Tuple::new_point(0.0, 0.0, -1.0)));
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    w.color_at(&r);
+}
+
+// pg 114
+#[test]
+fn test_book_shade_hit_in_shadow() {
+    let s1 = Sphere::new_unit();
+    let mut s2 = Sphere::new_unit();
+    s2.set_transform(Matrix4::translation(0.0, 0.0, 10.0)).unwrap();
+    let w = World::new(
+        vec![Light::new_point(Tuple::new_point(0.0, 0.0, -10.0), Color::white(1.0))],
+        vec![Box::new(s1), Box::new(s2)],
+    );
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, 5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let i = Intersection::new(4.0, w.objects[1].as_ref());
+    let comps = i.precompute(&r);
+    assert_eq!(w.shade_hit(&comps), Color::white(0.1));
+}
+
+#[test]
+fn test_shade_hit_sums_lights() {
+    let mut w = World::default();
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let single = w.color_at(&r);
+    w.lights.push(Light::new_point(Tuple::new_point(-10.0, 10.0, -10.0), Color::white(1.0)));
+    assert_eq!(w.color_at(&r), single * 2.0);
+}
+
+#[test]
+fn test_shade_hit_shadow_per_light() {
+    let s1 = Sphere::new_unit();
+    let mut s2 = Sphere::new_unit();
+    s2.set_transform(Matrix4::translation(0.0, 0.0, 10.0)).unwrap();
+    let w = World::new(
+        vec![
+            Light::new_point(Tuple::new_point(0.0, 0.0, -10.0), Color::white(1.0)),
+            Light::new_point(Tuple::new_point(0.0, 0.0, 5.0), Color::white(1.0)),
+        ],
+        vec![Box::new(s1), Box::new(s2)],
+    );
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, 5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let i = Intersection::new(4.0, w.objects[1].as_ref());
+    let comps = i.precompute(&r);
+    // the first light is blocked by s1 and only contributes ambient; the second lights s2 head-on
+    assert_eq!(w.shade_hit(&comps), Color::white(0.1) + Color::white(1.9));
+}
+
+fn reflective_floor(reflective: Float) -> Plane {
+    let mut floor = Plane::new();
+    floor.mut_material().reflective = reflective;
+    floor.set_transform(Matrix4::translation(0.0, -1.0, 0.0)).unwrap();
+    floor
+}
+
+// pg 144
+#[test]
+fn test_book_reflected_color_nonreflective() {
+    let mut w = World::default();
+    w.objects[1].mut_material().ambient = 1.0;
+    let r = Ray::new(Tuple::origin_point(), Tuple::new_vector(0.0, 0.0, 1.0));
+    let i = Intersection::new(1.0, w.objects[1].as_ref());
+    let comps = i.precompute(&r);
+    assert_eq!(w.reflected_color(&comps, DEFAULT_MAX_DEPTH), BLACK);
+}
+
+// pg 144
+#[test]
+fn test_book_reflected_color_reflective() {
+    let mut w = World::default();
+    w.objects.push(Box::new(reflective_floor(0.5)));
+    let half = (2.0 as Float).sqrt() / 2.0;
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -3.0), Tuple::new_vector(0.0, -half, half));
+    let i = Intersection::new((2.0 as Float).sqrt(), w.objects[2].as_ref());
+    let comps = i.precompute(&r);
+    assert_eq!(w.reflected_color(&comps, DEFAULT_MAX_DEPTH), Color::new(0.19033, 0.23791, 0.14274));
+}
+
+// pg 145
+#[test]
+fn test_book_shade_hit_reflective() {
+    let mut w = World::default();
+    w.objects.push(Box::new(reflective_floor(0.5)));
+    let half = (2.0 as Float).sqrt() / 2.0;
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -3.0), Tuple::new_vector(0.0, -half, half));
+    let i = Intersection::new((2.0 as Float).sqrt(), w.objects[2].as_ref());
+    let comps = i.precompute(&r);
+    assert_eq!(w.shade_hit(&comps), Color::new(0.87675, 0.92434, 0.82917));
+}
+
+// pg 146
+#[test]
+fn test_book_color_at_mutually_reflective() {
+    let mut lower = Plane::new();
+    lower.mut_material().reflective = 1.0;
+    lower.set_transform(Matrix4::translation(0.0, -1.0, 0.0)).unwrap();
+    let mut upper = Plane::new();
+    upper.mut_material().reflective = 1.0;
+    upper.set_transform(Matrix4::translation(0.0, 1.0, 0.0)).unwrap();
+    let w = World::new(
+        vec![Light::new_point(Tuple::origin_point(), Color::white(1.0))],
+        vec![Box::new(lower), Box::new(upper)],
+    );
+    let r = Ray::new(Tuple::origin_point(), Tuple::new_vector(0.0, 1.0, 0.0));
+    // terminating at all is the test
+    w.color_at(&r);
+}
+
+// pg 147
+#[test]
+fn test_book_reflected_color_max_depth() {
+    let mut w = World::default();
+    w.objects.push(Box::new(reflective_floor(0.5)));
+    let half = (2.0 as Float).sqrt() / 2.0;
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -3.0), Tuple::new_vector(0.0, -half, half));
+    let i = Intersection::new((2.0 as Float).sqrt(), w.objects[2].as_ref());
+    let comps = i.precompute(&r);
+    assert_eq!(w.reflected_color(&comps, 0), BLACK);
+}
+
+#[test]
+fn test_world_max_depth_limits_bounces() {
+    let mut w = World::default();
+    w.objects.push(Box::new(reflective_floor(0.5)));
+    let half = (2.0 as Float).sqrt() / 2.0;
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -3.0), Tuple::new_vector(0.0, -half, half));
+    assert_eq!(w.max_depth, DEFAULT_MAX_DEPTH);
+    let reflected = w.color_at(&r);
+    w.max_depth = 0;
+    let flat = w.color_at(&r);
+    assert_eq!(reflected - flat, Color::new(0.19033, 0.23791, 0.14274));
+}
+
+// pg 155
+#[test]
+fn test_book_refracted_color_opaque() {
+    let w = World::default();
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let xs = w.intersections(&r);
+    let comps = xs[0].precompute_with_intersections(&r, &xs);
+    assert_eq!(w.refracted_color(&comps, DEFAULT_MAX_DEPTH), BLACK);
+}
+
+// pg 156
+#[test]
+fn test_book_refracted_color_max_depth() {
+    let mut w = World::default();
+    w.objects[0].mut_material().transparency = 1.0;
+    w.objects[0].mut_material().refractive_index = 1.5;
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let xs = w.intersections(&r);
+    let comps = xs[0].precompute_with_intersections(&r, &xs);
+    assert_eq!(w.refracted_color(&comps, 0), BLACK);
+}
+
+// In book: test_pattern()
+#[derive(Debug)]
+struct TestPattern {
+    space: PatternSpace,
+}
+
+impl Pattern for TestPattern {
+    fn space(&self) -> &PatternSpace {
+        &self.space
+    }
+    fn mut_space(&mut self) -> &mut PatternSpace {
+        &mut self.space
+    }
+    fn local_color_at(&self, point: &Point) -> Color {
+        Color::new(point.x, point.y, point.z)
+    }
+}
+
+// pg 158
+#[test]
+fn test_book_refracted_color_refracted_ray() {
+    let mut w = World::default();
+    w.objects[0].mut_material().ambient = 1.0;
+    w.objects[0].mut_material().set_pattern(TestPattern { space: PatternSpace::new() });
+    w.objects[1].mut_material().transparency = 1.0;
+    w.objects[1].mut_material().refractive_index = 1.5;
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, 0.1), Tuple::new_vector(0.0, 1.0, 0.0));
+    let xs = vec![
+        Intersection::new(-0.9899, w.objects[0].as_ref()),
+        Intersection::new(-0.4899, w.objects[1].as_ref()),
+        Intersection::new(0.4899, w.objects[1].as_ref()),
+        Intersection::new(0.9899, w.objects[0].as_ref()),
+    ];
+    let comps = xs[2].precompute_with_intersections(&r, &xs);
+    // the book has 0.04725 for blue, which comes from its larger EPSILON of 0.0001
+    assert_eq!(w.refracted_color(&comps, DEFAULT_MAX_DEPTH), Color::new(0.0, 0.99888, 0.04722));
+}
+
+// pg 157
+#[test]
+fn test_book_refracted_color_total_internal_reflection() {
+    let mut w = World::default();
+    w.objects[0].mut_material().transparency = 1.0;
+    w.objects[0].mut_material().refractive_index = 1.5;
+    let half = (2.0 as Float).sqrt() / 2.0;
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, half), Tuple::new_vector(0.0, 1.0, 0.0));
+    let xs = vec![
+        Intersection::new(-half, w.objects[0].as_ref()),
+        Intersection::new(half, w.objects[0].as_ref()),
+    ];
+    // inside the sphere, so look at the second intersection
+    let comps = xs[1].precompute_with_intersections(&r, &xs);
+    assert_eq!(w.refracted_color(&comps, DEFAULT_MAX_DEPTH), BLACK);
+}
+
+fn world_with_glass_floor(reflective: Float) -> World {
+    let mut w = World::default();
+    let mut floor = reflective_floor(reflective);
+    floor.mut_material().transparency = 0.5;
+    floor.mut_material().refractive_index = 1.5;
+    let mut ball = Sphere::new_unit();
+    ball.mut_material().color = Color::new(1.0, 0.0, 0.0);
+    ball.mut_material().ambient = 0.5;
+    ball.set_transform(Matrix4::translation(0.0, -3.5, -0.5)).unwrap();
+    w.objects.push(Box::new(floor));
+    w.objects.push(Box::new(ball));
+    w
+}
+
+// pg 159
+#[test]
+fn test_book_shade_hit_transparent() {
+    let w = world_with_glass_floor(0.0);
+    let half = (2.0 as Float).sqrt() / 2.0;
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -3.0), Tuple::new_vector(0.0, -half, half));
+    let xs = vec![Intersection::new((2.0 as Float).sqrt(), w.objects[2].as_ref())];
+    let comps = xs[0].precompute_with_intersections(&r, &xs);
+    assert_eq!(w.shade_hit(&comps), Color::new(0.93642, 0.68642, 0.68642));
+}
+
+// pg 164
+#[test]
+fn test_book_shade_hit_reflective_transparent() {
+    let w = world_with_glass_floor(0.5);
+    let half = (2.0 as Float).sqrt() / 2.0;
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -3.0), Tuple::new_vector(0.0, -half, half));
+    let xs = vec![Intersection::new((2.0 as Float).sqrt(), w.objects[2].as_ref())];
+    let comps = xs[0].precompute_with_intersections(&r, &xs);
+    assert_eq!(w.shade_hit(&comps), Color::new(0.93391, 0.69643, 0.69243));
+}
+
+#[test]
+fn test_glass_sphere_in_front_of_wall_lets_color_through() {
+    let mut wall = Plane::new();
+    wall.mut_material().color = Color::new(1.0, 0.0, 0.0);
+    wall.set_transform(Matrix4::identity().rotate_x(std::f64::consts::PI / 2.0).translate(0.0, 0.0, 5.0)).unwrap();
+    let mut w = World::new(
+        // off to the side, so the sphere's shadow isn't where we're looking
+        vec![Light::new_point(Tuple::new_point(0.0, 10.0, -10.0), Color::white(1.0))],
+        vec![Box::new(wall)],
+    );
+    let r = Ray::new(Tuple::new_point(0.0, 0.0, -5.0), Tuple::new_vector(0.0, 0.0, 1.0));
+    let bare = w.color_at(&r);
+    // perfectly clear glass, so anything we see has come through it
+    let mut glass = Sphere::glass();
+    glass.mut_material().ambient = 0.0;
+    glass.mut_material().diffuse = 0.0;
+    glass.mut_material().specular = 0.0;
+    w.objects.push(Box::new(glass));
+    let through_glass = w.color_at(&r);
+    // straight through the middle of the sphere: the wall is still mostly what we see
+    assert!(through_glass.red > 0.5 * bare.red);
+    assert!(through_glass.green < 0.1);
+}